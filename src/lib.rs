@@ -0,0 +1,45 @@
+//! A simulation of the cooperative board game Forbidden Island.
+//!
+//! A [`GameBoard`] holds the whole state of one game: the island tiles, the water meter,
+//! the treasure and flood decks, and the adventurers with their hands. Games are played by
+//! asking the board for the legal [`Action`]s at each point and applying one of them,
+//! drawing treasure and flood cards between turns, until [`GameBoard::outcome`] reports
+//! the game is over. [`play_game`] does this for a whole game, leaving every decision to
+//! an [`Agent`], and [`run_batch`] plays many games to gather win statistics.
+//!
+//! ```
+//! use forbidden_island_simulation::{Difficulty, GameBoard, GameOutcome};
+//! use rand::SeedableRng;
+//! use rand_chacha::ChaChaRng;
+//!
+//! let mut rng = ChaChaRng::seed_from_u64(1);
+//! let mut game_board = GameBoard::new(&mut rng, 4, Difficulty::Normal);
+//!
+//! let adventurer = game_board.current_adventurer();
+//! let options = game_board.get_options(&adventurer, game_board.actions_left());
+//! game_board.apply(options[0].clone()).unwrap();
+//! assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+//! ```
+#![warn(missing_docs)]
+
+#[macro_use]
+mod structs;
+
+pub use structs::{
+    action, agent, cards, coord, game_board, outcome, rules, simulation, strategy, turn,
+    water_meter,
+};
+
+pub use structs::{
+    action::{Action, ActionError},
+    agent::Agent,
+    cards::{Card, CardType, Deck},
+    coord::Coord,
+    game_board::GameBoard,
+    outcome::{GameOutcome, LossReason},
+    rules::NavigatorRule,
+    simulation::{game_rng, play_game, run_batch, BatchConfig, BatchReport, GameRecord},
+    strategy::{GreedyAgent, RandomAgent, Strategy},
+    turn::{Decision, TurnError, TurnPhase},
+    water_meter::{Difficulty, WaterMeter},
+};
@@ -1,46 +1,65 @@
-use rand::SeedableRng;
-use structs::game_board::GameBoard;
+use std::{env, process, thread};
 
-use crate::structs::cards::{
-    adventurer::{AdventurerCard, AdventurerCardType},
-    flood::FloodCard,
-    island::{IslandCard, IslandCardName},
-    treasure::{TreasureCard, TreasureCardType, TreasureType},
-    Card,
+use forbidden_island_simulation::{
+    game_board::{MAX_PLAYERS, MIN_PLAYERS},
+    run_batch, BatchConfig, Difficulty, Strategy,
 };
 
-#[macro_use]
-mod structs;
+const USAGE: &str = "Usage: forbidden_island_simulation [--games N] [--seed SEED] \
+[--players 2-4] [--difficulty novice|normal|elite|legendary] [--strategy random|greedy] \
+[--threads N]";
 
-fn main() {
-    // println!("Hello, world!");
-    let mut game_board = GameBoard::new(&mut rand_chacha::ChaChaRng::seed_from_u64(1), 2);
+/// Finds the variant whose name matches `name`, ignoring case
+fn parse_variant<T>(mut variants: impl Iterator<Item = T>, name: &str) -> Option<T>
+where
+    T: std::fmt::Debug,
+{
+    variants.find(|variant| format!("{variant:?}").eq_ignore_ascii_case(name))
+}
+
+fn parse_args(args: &[String]) -> Option<(BatchConfig, Strategy)> {
+    let mut config = BatchConfig {
+        seed: 0,
+        games: 1000,
+        player_count: 4,
+        difficulty: Difficulty::Normal,
+        threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+    };
+    let mut strategy = Strategy::Greedy;
 
-    game_board.sink(&IslandCardName::CopperGate);
-    game_board
-        .adventurer_locations
-        .entry(AdventurerCardType::Diver)
-        .and_modify(|(a, pos)| {
-            a.receive_card(TreasureCard::new(&TreasureCardType::Treasure(
-                TreasureType::Earth,
-            )));
-            a.receive_card(TreasureCard::new(&TreasureCardType::Treasure(
-                TreasureType::Earth,
-            )));
-            a.receive_card(TreasureCard::new(&TreasureCardType::Treasure(
-                TreasureType::Earth,
-            )));
-            a.receive_card(TreasureCard::new(&TreasureCardType::Treasure(
-                TreasureType::Earth,
-            )));
-            *pos = (2, 3);
-        });
-    println!("{}", game_board.show_board());
+    for pair in args.chunks(2) {
+        let [flag, value] = pair else {
+            return None;
+        };
+        match flag.as_str() {
+            "--games" => config.games = value.parse().ok()?,
+            "--seed" => config.seed = value.parse().ok()?,
+            "--players" => config.player_count = value.parse().ok()?,
+            "--difficulty" => {
+                config.difficulty = parse_variant(Difficulty::iter().copied(), value)?
+            }
+            "--strategy" => strategy = parse_variant(Strategy::iter().copied(), value)?,
+            "--threads" => config.threads = value.parse().ok().filter(|&threads| threads > 0)?,
+            _ => return None,
+        }
+    }
+    if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&config.player_count) {
+        return None;
+    }
+    Some((config, strategy))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some((config, strategy)) = parse_args(&args) else {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    };
 
-    dbg!(game_board.get_options(&AdventurerCardType::Diver, 3));
-    // dbg!(game_board.get_moves(&AdventurerCardType::Pilot));
-    // dbg!(game_board.get_adjacent(&IslandCardName::DunesOfDeception));
-    // game_board.shore_up(&IslandCardName::CopperGate);
-    // // game_board.sink(&IslandCardName::CopperGate);
-    // dbg!(game_board.get_adjacent(&IslandCardName::DunesOfDeception));
+    println!(
+        "Playing {} games with {} players on {:?} difficulty, using the {:?} strategy \
+        on {} threads\n",
+        config.games, config.player_count, config.difficulty, strategy, config.threads
+    );
+    println!("{}", run_batch(&config, |rng| strategy.agent(rng)));
 }
@@ -0,0 +1,179 @@
+use std::fmt;
+
+use super::{
+    cards::{
+        adventurer::AdventurerCardType,
+        island::IslandCardName,
+        treasure::{TreasureCardType, TreasureType},
+    },
+    coord::Coord,
+    turn::TurnError,
+};
+
+/// Everything an adventurer can do to change the state of a `GameBoard`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Move the current adventurer to the tile at the given coordinate
+    Move(Coord),
+    /// Shore up a flooded tile within the current adventurer's reach
+    ShoreUp(IslandCardName),
+    /// The Navigator moves another adventurer up to two tiles
+    Navigate {
+        /// The adventurer being moved
+        adventurer: AdventurerCardType,
+        /// Where they are moved to
+        to: Coord,
+    },
+    /// Give one of the current adventurer's treasure cards to another adventurer
+    GiveCard {
+        /// The adventurer receiving the card, who must be on the same tile
+        to: AdventurerCardType,
+        /// The treasure on the card
+        treasure: TreasureType,
+    },
+    /// Capture a treasure by handing in four of its cards on one of its tiles
+    CaptureTreasure(TreasureType),
+    /// End the action phase, forfeiting any unused actions
+    Pass,
+    /// Play a Sandbag from any adventurer's hand, at any time, to shore up any flooded tile
+    PlaySandbag {
+        /// The adventurer holding the Sandbag
+        adventurer: AdventurerCardType,
+        /// The tile to shore up
+        tile: IslandCardName,
+    },
+    /// Play a Helicopter Lift from any adventurer's hand, at any time, to fly one or more
+    /// adventurers sharing a tile to any tile that has not sunk
+    PlayHelicopterLift {
+        /// The adventurer holding the Helicopter Lift
+        adventurer: AdventurerCardType,
+        /// The adventurers flown, all standing on the same tile
+        passengers: Vec<AdventurerCardType>,
+        /// Where they are flown to
+        to: Coord,
+    },
+    /// Play a Helicopter Lift to fly everyone off Fools' Landing once all four treasures
+    /// have been captured, winning the game
+    Escape {
+        /// The adventurer holding the Helicopter Lift
+        adventurer: AdventurerCardType,
+    },
+    /// Swim to safety from a tile that sank under the adventurer
+    Swim {
+        /// The adventurer whose tile sank
+        adventurer: AdventurerCardType,
+        /// Where they swim to
+        to: Coord,
+    },
+    /// Discard a card from an adventurer's hand to get back under the hand limit
+    Discard {
+        /// The adventurer over the hand limit
+        adventurer: AdventurerCardType,
+        /// The card they discard
+        card: TreasureCardType,
+    },
+}
+
+impl Action {
+    /// Returns `true` if the action plays a special card, which can be done at any time
+    pub fn is_special_card(&self) -> bool {
+        matches!(
+            self,
+            Action::PlaySandbag { .. } | Action::PlayHelicopterLift { .. } | Action::Escape { .. }
+        )
+    }
+}
+
+/// Why an action was rejected by `GameBoard::apply`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionError {
+    /// The action is not allowed at this point in the turn
+    Turn(TurnError),
+    /// The current adventurer cannot move to the coordinate
+    IllegalMove(Coord),
+    /// Only the Navigator can move other adventurers
+    NotNavigator,
+    /// The tile is not flooded, so cannot be shored up
+    NotFlooded(IslandCardName),
+    /// The tile is too far from the adventurer
+    OutOfReach(IslandCardName),
+    /// The treasure has already been captured
+    AlreadyCaptured(TreasureType),
+    /// The adventurer is not standing on a tile the treasure can be captured from
+    NotOnTreasureTile(TreasureType),
+    /// The adventurer holds fewer than four of the treasure's cards
+    NotEnoughTreasureCards(TreasureType),
+    /// The adventurer is not over the hand limit, so has nothing to discard
+    NotDiscarding(AdventurerCardType),
+    /// The adventurer's tile has not sunk, so they have no need to swim
+    NotSwimming(AdventurerCardType),
+    /// The adventurer does not hold a card of this type
+    CardNotInHand(TreasureCardType),
+    /// The adventurer is not taking part in this game
+    NotInGame(AdventurerCardType),
+    /// Cards cannot be given to the adventurer giving them
+    GiveToSelf,
+    /// Only the Messenger can give cards to an adventurer on another tile
+    NotOnSameTile(AdventurerCardType),
+    /// A Helicopter Lift must carry at least one adventurer
+    NoPassengers,
+    /// The adventurer cannot be carried, as they are not on the same tile as the others
+    PassengerApart(AdventurerCardType),
+    /// The helicopter cannot land on the coordinate
+    IllegalLanding(Coord),
+    /// Not every treasure has been captured and every adventurer gathered on Fools' Landing
+    CannotEscape,
+}
+
+impl From<TurnError> for ActionError {
+    fn from(error: TurnError) -> Self {
+        ActionError::Turn(error)
+    }
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionError::Turn(error) => write!(f, "{error}"),
+            ActionError::IllegalMove(coord) => write!(f, "cannot move to {coord}"),
+            ActionError::NotNavigator => write!(f, "only the Navigator can move others"),
+            ActionError::NotFlooded(tile) => write!(f, "{tile:?} is not flooded"),
+            ActionError::OutOfReach(tile) => write!(f, "{tile:?} is out of reach"),
+            ActionError::AlreadyCaptured(treasure) => {
+                write!(f, "the {treasure:?} treasure has already been captured")
+            }
+            ActionError::NotOnTreasureTile(treasure) => {
+                write!(f, "the {treasure:?} treasure cannot be captured here")
+            }
+            ActionError::NotEnoughTreasureCards(treasure) => {
+                write!(f, "not enough {treasure:?} cards to capture the treasure")
+            }
+            ActionError::NotDiscarding(adventurer) => {
+                write!(f, "{adventurer:?} does not need to discard")
+            }
+            ActionError::NotSwimming(adventurer) => {
+                write!(f, "{adventurer:?} does not need to swim")
+            }
+            ActionError::CardNotInHand(card) => write!(f, "no {card:?} card in hand"),
+            ActionError::NotInGame(adventurer) => write!(f, "{adventurer:?} is not in the game"),
+            ActionError::GiveToSelf => write!(f, "cannot give a card to yourself"),
+            ActionError::NotOnSameTile(adventurer) => {
+                write!(f, "{adventurer:?} is not on the same tile")
+            }
+            ActionError::NoPassengers => write!(f, "the helicopter has no passengers"),
+            ActionError::PassengerApart(adventurer) => {
+                write!(
+                    f,
+                    "{adventurer:?} is not on the same tile as the other passengers"
+                )
+            }
+            ActionError::IllegalLanding(coord) => write!(f, "cannot land at {coord}"),
+            ActionError::CannotEscape => write!(
+                f,
+                "every treasure must be captured and every adventurer on Fools' Landing"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ActionError {}
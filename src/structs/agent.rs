@@ -0,0 +1,47 @@
+use super::{action::Action, cards::adventurer::AdventurerCardType, game_board::GameBoard};
+
+/// Makes every decision for the adventurers in a game. Each method is given a read-only
+/// view of the game and the legal options, and must return one of them
+pub trait Agent {
+    /// Picks the current adventurer's next action during the action phase
+    fn choose_action(
+        &mut self,
+        game_board: &GameBoard,
+        adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Action;
+
+    /// Picks how the adventurer gets back under the hand limit, by discarding a card or
+    /// playing a special card. Defaults to `choose_action`
+    fn choose_discard(
+        &mut self,
+        game_board: &GameBoard,
+        adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Action {
+        self.choose_action(game_board, adventurer, options)
+    }
+
+    /// Picks where the adventurer swims to after their tile sank, or a special card to
+    /// play instead. Any adventurer's Helicopter Lift that could fly them to safety is
+    /// offered too. Defaults to `choose_action`
+    fn choose_swim(
+        &mut self,
+        game_board: &GameBoard,
+        adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Action {
+        self.choose_action(game_board, adventurer, options)
+    }
+
+    /// Offers an adventurer who is not otherwise deciding the chance to play a special
+    /// card, returning `None` to hold on to it. Defaults to never playing one
+    fn choose_special(
+        &mut self,
+        _game_board: &GameBoard,
+        _adventurer: &AdventurerCardType,
+        _options: &[Action],
+    ) -> Option<Action> {
+        None
+    }
+}
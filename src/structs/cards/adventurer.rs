@@ -1,16 +1,20 @@
 use std::slice::Iter;
 
-use super::{island::IslandCardName, treasure::TreasureCard, Card, CardType, Deck};
+use super::{
+    island::IslandCardName,
+    treasure::{TreasureCard, TreasureCardType},
+    Card, CardType, Deck,
+};
 
-#[allow(dead_code)]
+/// An adventurer in the game and the treasure cards in their hand
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AdventurerCard {
     card_type: AdventurerCardType,
     treasure_hand: Deck<TreasureCard>,
 }
 
-#[allow(dead_code)]
 impl AdventurerCard {
+    /// Returns the adventurer with an empty hand
     pub fn new(&card_type: &AdventurerCardType) -> Self {
         AdventurerCard {
             card_type,
@@ -18,6 +22,7 @@ impl AdventurerCard {
         }
     }
 
+    /// Returns the tile the adventurer starts the game on
     pub fn get_start_card(&self) -> IslandCardName {
         match self.card_type {
             AdventurerCardType::Explorer => IslandCardName::CopperGate,
@@ -29,34 +34,63 @@ impl AdventurerCard {
         }
     }
 
+    /// Adds a card to the adventurer's hand
     pub fn receive_card(&mut self, card: TreasureCard) {
         self.treasure_hand.insert(card);
     }
 
+    /// Returns the number of cards in the adventurer's hand
     pub fn get_card_count(&self) -> usize {
         self.treasure_hand.len()
     }
 
+    /// Returns the number of cards of `card_type` in the adventurer's hand
+    pub fn count_cards(&self, card_type: &TreasureCardType) -> usize {
+        self.treasure_hand
+            .iter()
+            .filter(|card| &card.get_type() == card_type)
+            .count()
+    }
+
+    /// Removes and returns a card of `card_type` from the adventurer's hand
+    pub fn remove_card(&mut self, card_type: &TreasureCardType) -> Option<TreasureCard> {
+        let index = self
+            .treasure_hand
+            .iter()
+            .position(|card| &card.get_type() == card_type)?;
+        self.treasure_hand.pop_card(index)
+    }
+
+    /// Returns which adventurer this is
     pub fn get_type(&self) -> AdventurerCardType {
         self.card_type
     }
 
+    /// Returns the treasure cards in the adventurer's hand
     pub fn get_hand(&self) -> &Deck<TreasureCard> {
         &self.treasure_hand
     }
 }
 
+/// The six adventurers, each with their own special ability
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AdventurerCardType {
+    /// Can move and shore up diagonally
     Explorer,
+    /// Can fly to any tile once per turn
     Pilot,
+    /// Can shore up two tiles for one action
     Engineer,
+    /// Can move through any number of flooded or sunk tiles for one action
     Diver,
+    /// Can give treasure cards to an adventurer on any tile
     Messenger,
+    /// Can move other adventurers, see `NavigatorRule`
     Navigator,
 }
 
 impl AdventurerCardType {
+    /// Returns every adventurer
     pub const fn all() -> [AdventurerCardType; 6] {
         [
             AdventurerCardType::Explorer,
@@ -68,6 +102,7 @@ impl AdventurerCardType {
         ]
     }
 
+    /// Returns an iterator over every adventurer
     pub fn iter() -> Iter<'static, AdventurerCardType> {
         static ALL_CARD_NAMES: [AdventurerCardType; 6] = AdventurerCardType::all();
         ALL_CARD_NAMES.iter()
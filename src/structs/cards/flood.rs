@@ -1,20 +1,26 @@
 use super::{island::IslandCardName, Card, CardType, Deck};
 
-#[allow(dead_code)]
+/// A card from the flood deck, naming the tile that floods when it is drawn
 #[derive(Debug, Clone)]
 pub struct FloodCard {
     island_card: IslandCardName,
 }
 
-#[allow(dead_code)]
 impl FloodCard {
+    /// Returns the flood card for the tile
     pub fn new(island_card: IslandCardName) -> FloodCard {
         FloodCard { island_card }
     }
 
+    /// Returns the flood card for the tile
     pub fn from_name(&name: &IslandCardName) -> FloodCard {
         FloodCard { island_card: name }
     }
+
+    /// Returns the tile the card floods
+    pub fn island_card(&self) -> IslandCardName {
+        self.island_card
+    }
 }
 
 impl Card for FloodCard {
@@ -1,11 +1,14 @@
 use super::{treasure::TreasureType, Card, CardType, Deck};
 use std::slice::Iter;
 
-#[allow(dead_code)]
+/// How far a tile has gone under
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IslandCardState {
+    /// Dry, and can be walked on
     Normal,
+    /// Flooded, but can still be walked on and shored up
     Flooded,
+    /// Gone for good, nobody can stand on it
     Sunk,
 }
 
@@ -35,34 +38,60 @@ impl IslandCardState {
     }
 }
 
+/// The names of the 24 tiles the island is made of
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum IslandCardName {
+    /// Cliffs of Abandon
     CliffsOfAbandon,
+    /// Watchtower
     Watchtower,
+    /// Phantom Rock
     PhantomRock,
+    /// Lost Lagoon
     LostLagoon,
+    /// Misty Marsh
     MistyMarsh,
+    /// Twilight Hollow
     TwilightHollow,
+    /// Crimson Forest
     CrimsonForest,
+    /// Observatory
     Observatory,
+    /// Breakers Bridge
     BreakersBridge,
+    /// Dunes of Deception
     DunesOfDeception,
+    /// Fools' Landing, where the helicopter escapes from
     FoolsLanding,
+    /// Bronze Gate, where the Engineer starts
     BronzeGate,
+    /// Gold Gate, where the Navigator starts
     GoldGate,
+    /// Silver Gate, where the Messenger starts
     SilverGate,
+    /// Copper Gate, where the Explorer starts
     CopperGate,
+    /// Iron Gate, where the Diver starts
     IronGate,
+    /// Temple of the Sun, where the Earth Stone can be captured
     TempleOfTheSun,
+    /// Temple of the Moon, where the Earth Stone can be captured
     TempleOfTheMoon,
+    /// Whispering Garden, where the Statue of the Wind can be captured
     WhisperingGarden,
+    /// Howling Garden, where the Statue of the Wind can be captured
     HowlingGarden,
+    /// Cave of Embers, where the Crystal of Fire can be captured
     CaveOfEmbers,
+    /// Cave of Shadows, where the Crystal of Fire can be captured
     CaveOfShadows,
+    /// Tidal Palace, where the Ocean's Chalice can be captured
     TidalPalace,
+    /// Coral Palace, where the Ocean's Chalice can be captured
     CoralPalace,
 }
 impl IslandCardName {
+    /// Returns every tile name
     pub const fn all() -> [IslandCardName; 24] {
         [
             IslandCardName::CliffsOfAbandon,
@@ -91,11 +120,13 @@ impl IslandCardName {
             IslandCardName::CoralPalace,
         ]
     }
+    /// Returns an iterator over every tile name
     pub fn iter() -> Iter<'static, IslandCardName> {
         static ALL_CARD_NAMES: [IslandCardName; 24] = IslandCardName::all();
         ALL_CARD_NAMES.iter()
     }
 
+    /// Returns the three character name printed on the tile by `GameBoard::show_board`
     pub fn shorthand(&self) -> &'static str {
         match self {
             IslandCardName::CliffsOfAbandon => "C A",
@@ -126,15 +157,15 @@ impl IslandCardName {
     }
 }
 
-#[allow(dead_code)]
+/// A tile of the island and how far it has flooded
 #[derive(Debug, Clone, Copy)]
 pub struct IslandCard {
     state: IslandCardState,
     name: IslandCardName,
 }
 
-#[allow(dead_code)]
 impl IslandCard {
+    /// Returns the tile, not yet flooded
     pub fn new(name: IslandCardName) -> IslandCard {
         IslandCard {
             state: IslandCardState::Normal,
@@ -142,27 +173,33 @@ impl IslandCard {
         }
     }
 
+    /// Returns the tile, not yet flooded
     pub fn from_name(&name: &IslandCardName) -> IslandCard {
         IslandCard::new(name)
     }
 
+    /// Returns how far the tile has flooded
     pub fn state(&self) -> &IslandCardState {
         &self.state
     }
 
+    /// Returns the tile's name
     pub fn name(&self) -> IslandCardName {
         self.name
     }
 
+    /// Floods the tile, or sinks it if it is already flooded. Panics if it has sunk
     pub fn sink(&mut self) {
         self.state.step();
     }
 
+    /// Shores up a flooded tile. Panics if it is not flooded
     pub fn raise(&mut self) {
         assert!(self.state != IslandCardState::Sunk);
         self.state.step_back()
     }
 
+    /// Returns the tile drawn as three lines of text
     pub fn tile_str(&self) -> String {
         let (horizontal_sep, vertical_sep) = match self.state {
             IslandCardState::Normal => ("===", "║"),
@@ -173,6 +210,7 @@ impl IslandCard {
         format!("+{horizontal_sep}+\n{vertical_sep}{shorthand}{vertical_sep}\n+{horizontal_sep}+")
     }
 
+    /// Returns `true` if `treasure` can be captured from the tile
     pub fn can_retrieve(&self, treasure: &TreasureType) -> bool {
         match self.name {
             IslandCardName::TempleOfTheSun | IslandCardName::TempleOfTheMoon => {
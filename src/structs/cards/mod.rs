@@ -1,29 +1,43 @@
+/// The adventurers and the treasure cards they hold
 pub mod adventurer;
+/// The flood deck, which decides which tiles flood
 pub mod flood;
+/// The tiles the island is made of
 pub mod island;
+/// The treasure deck and the four treasures
 pub mod treasure;
 
 use std::slice::Iter;
 
 use rand::{seq::SliceRandom, Rng};
+
+/// The four kinds of card in the game
 pub enum CardType {
+    /// A tile of the island
     Island,
+    /// A card from the treasure deck
     Treasure,
+    /// A card from the flood deck
     Flood,
+    /// An adventurer
     Adventurer,
 }
 
+/// A kind of card that comes in a full deck at the start of the game
 pub trait Card: Clone {
+    /// Returns which kind of card this is
     fn card_type() -> CardType;
+    /// Returns every card of this kind, in a fixed order
     fn get_deck() -> Deck<Self>;
 }
 
+/// A pile of cards, with the top of the pile at index 0
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Deck<A: Card> {
+    /// The cards in the pile, top first
     pub cards: Vec<A>,
 }
 
-#[allow(dead_code)]
 impl<T: Card> Deck<T> {
     /// Copies `cards` into a new `Deck<T>`.
     pub fn from(cards: &[T]) -> Deck<T> {
@@ -87,7 +101,7 @@ impl<T: Card> Deck<T> {
     }
 
     /// Returns an iterator over the deck of cards
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         self.cards.iter()
     }
 
@@ -102,6 +116,13 @@ impl<T: Card> Deck<T> {
     }
 }
 
+impl<T: Card> Default for Deck<T> {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
+
+/// Creates a `Deck` holding the given cards, top first
 #[macro_export]
 macro_rules! deck {
     () => {
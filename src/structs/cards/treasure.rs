@@ -2,15 +2,21 @@ use std::slice::Iter;
 
 use super::{Card, CardType, Deck};
 
+/// The four treasures to be captured
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TreasureType {
+    /// The Earth Stone
     Earth,
+    /// The Statue of the Wind
     Wind,
+    /// The Crystal of Fire
     Fire,
+    /// The Ocean's Chalice
     Ocean,
 }
 
 impl TreasureType {
+    /// Returns every treasure
     pub const fn all() -> [TreasureType; 4] {
         [
             TreasureType::Earth,
@@ -20,21 +26,28 @@ impl TreasureType {
         ]
     }
 
+    /// Returns an iterator over every treasure
     pub fn iter() -> Iter<'static, TreasureType> {
         static ALL_TREASURE_TYPES: [TreasureType; 4] = TreasureType::all();
         ALL_TREASURE_TYPES.iter()
     }
 }
 
+/// The kinds of card in the treasure deck
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TreasureCardType {
+    /// One of the cards needed to capture a treasure
     Treasure(TreasureType),
+    /// Shores up any tile when played
     Sandbag,
+    /// Flies adventurers to any tile when played
     HelicopterLift,
+    /// Raises the water meter as soon as it is drawn
     WaterRise,
 }
 
 impl TreasureCardType {
+    /// Returns every card in a full treasure deck, duplicates included
     pub const fn all() -> [TreasureCardType; 28] {
         [
             TreasureCardType::Treasure(TreasureType::Earth),
@@ -68,24 +81,26 @@ impl TreasureCardType {
         ]
     }
 
+    /// Returns an iterator over every card in a full treasure deck
     pub fn iter() -> Iter<'static, TreasureCardType> {
         static ALL_CARD_TYPES: [TreasureCardType; 28] = TreasureCardType::all();
         ALL_CARD_TYPES.iter()
     }
 }
 
+/// A card from the treasure deck
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-#[allow(dead_code)]
 pub struct TreasureCard {
     treasure_type: TreasureCardType,
 }
 
-#[allow(dead_code)]
 impl TreasureCard {
+    /// Returns a card of the given kind
     pub fn new(&treasure_type: &TreasureCardType) -> TreasureCard {
         TreasureCard { treasure_type }
     }
 
+    /// Returns what kind of card this is
     pub fn get_type(&self) -> TreasureCardType {
         self.treasure_type
     }
@@ -0,0 +1,98 @@
+use std::fmt;
+
+/// The width and height of the grid the island is laid out on
+pub const GRID_SIZE: usize = 6;
+
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(0, 1), (1, 0), (-1, 0), (0, -1)];
+const DIAGONAL_OFFSETS: [(isize, isize); 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+/// A position on the grid the island is laid out on, with `(0, 0)` in the top left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    /// The column, counting from the left
+    pub x: usize,
+    /// The row, counting from the top
+    pub y: usize,
+}
+
+impl Coord {
+    /// Returns the coordinate in column `x` and row `y`
+    pub const fn new(x: usize, y: usize) -> Coord {
+        Coord { x, y }
+    }
+
+    /// Returns the coordinate `dx` across and `dy` down from `self`, or `None` if that is
+    /// off the grid
+    pub fn offset(&self, dx: isize, dy: isize) -> Option<Coord> {
+        let x = self.x.checked_add_signed(dx).filter(|&x| x < GRID_SIZE)?;
+        let y = self.y.checked_add_signed(dy).filter(|&y| y < GRID_SIZE)?;
+        Some(Coord { x, y })
+    }
+
+    /// Returns the coordinates above, below, left and right of `self` that are on the grid
+    pub fn orthogonal(&self) -> impl Iterator<Item = Coord> + '_ {
+        ORTHOGONAL_OFFSETS
+            .iter()
+            .filter_map(|&(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// Returns the coordinates diagonally next to `self` that are on the grid
+    pub fn diagonal(&self) -> impl Iterator<Item = Coord> + '_ {
+        DIAGONAL_OFFSETS
+            .iter()
+            .filter_map(|&(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// Returns all the coordinates orthogonally or diagonally next to `self`
+    pub fn surrounding(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.orthogonal().chain(self.diagonal())
+    }
+
+    /// Returns the number of orthogonal steps between `self` and `other`
+    pub fn distance(&self, other: &Coord) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns `true` if `other` is directly above, below, left or right of `self`
+    pub fn is_orthogonal_to(&self, other: &Coord) -> bool {
+        self.distance(other) == 1
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_neighbours() {
+        let corner = Coord::new(0, 0);
+        assert_eq!(
+            corner.orthogonal().collect::<Vec<_>>(),
+            vec![Coord::new(0, 1), Coord::new(1, 0)]
+        );
+        assert_eq!(
+            corner.diagonal().collect::<Vec<_>>(),
+            vec![Coord::new(1, 1)]
+        );
+
+        let corner = Coord::new(GRID_SIZE - 1, GRID_SIZE - 1);
+        assert_eq!(corner.surrounding().count(), 3);
+        assert_eq!(Coord::new(0, 2).surrounding().count(), 5);
+        assert_eq!(Coord::new(2, 2).surrounding().count(), 8);
+    }
+
+    #[test]
+    fn test_is_orthogonal_to() {
+        let coord = Coord::new(2, 3);
+        assert!(coord.is_orthogonal_to(&Coord::new(2, 2)));
+        assert!(coord.is_orthogonal_to(&Coord::new(3, 3)));
+        assert!(!coord.is_orthogonal_to(&Coord::new(3, 2)));
+        assert!(!coord.is_orthogonal_to(&coord));
+    }
+}
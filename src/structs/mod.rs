@@ -1,69 +1,131 @@
+/// Everything an adventurer can do on their turn, and why it may be refused
+pub mod action;
+/// The decision makers that play the game
+pub mod agent;
+/// The four kinds of card, and the `Deck` they are dealt from
 #[macro_use]
 pub mod cards;
+/// Positions on the grid the island is laid out on
+pub mod coord;
+/// Whether a game has been won or lost, and how
+pub mod outcome;
+/// Optional rule variants a game can be played with
+pub mod rules;
+/// Playing whole games, and batches of them, to gather win statistics
+pub mod simulation;
+/// The built in ways adventurers pick their actions
+pub mod strategy;
+/// The phases of a turn and the decisions that can interrupt them
+pub mod turn;
+/// The water meter and the difficulty it starts at
+pub mod water_meter;
 
-#[allow(dead_code)]
+/// The island, the decks and the adventurers, and the rules tying them together
 pub mod game_board {
 
-    use std::collections::{HashMap, HashSet};
+    use std::collections::{HashMap, HashSet, VecDeque};
 
     use rand::Rng;
 
     use crate::structs::cards::Card;
 
-    use super::cards::{
-        adventurer::{self, AdventurerCard, AdventurerCardType},
-        flood::FloodCard,
-        island::{IslandCard, IslandCardName, IslandCardState},
-        treasure::{self, TreasureCard, TreasureCardType, TreasureType},
-        Deck,
+    use super::{
+        action::{Action, ActionError},
+        cards::{
+            adventurer::{AdventurerCard, AdventurerCardType},
+            flood::FloodCard,
+            island::{IslandCard, IslandCardName, IslandCardState},
+            treasure::{TreasureCard, TreasureCardType, TreasureType},
+            Deck,
+        },
+        coord::{Coord, GRID_SIZE},
+        outcome::{GameOutcome, LossReason},
+        rules::NavigatorRule,
+        turn::{AbilityUsage, Decision, TurnError, TurnPhase},
+        water_meter::{Difficulty, WaterMeter},
     };
 
-    pub const ISLAND_COORDS: [(usize, usize); 24] = [
-        (2, 0),
-        (3, 0),
-        (1, 1),
-        (2, 1),
-        (3, 1),
-        (4, 1),
-        (0, 2),
-        (1, 2),
-        (2, 2),
-        (3, 2),
-        (4, 2),
-        (5, 2),
-        (0, 3),
-        (1, 3),
-        (2, 3),
-        (3, 3),
-        (4, 3),
-        (5, 3),
-        (1, 4),
-        (2, 4),
-        (3, 4),
-        (4, 4),
-        (2, 5),
-        (3, 5),
+    /// The number of actions an adventurer gets each turn
+    pub const ACTIONS_PER_TURN: usize = 3;
+    /// The number of matching treasure cards handed in to capture a treasure
+    pub const CARDS_TO_CAPTURE: usize = 4;
+    /// The number of treasure cards drawn at the end of each turn
+    pub const TREASURE_CARDS_PER_TURN: usize = 2;
+    /// The most cards an adventurer can hold, any more must be discarded
+    pub const HAND_LIMIT: usize = 5;
+    /// The number of treasure cards each adventurer is dealt at the start of the game
+    pub const STARTING_HAND_SIZE: usize = 2;
+    /// The number of tiles flooded at the start of the game
+    pub const STARTING_FLOOD_CARDS: usize = 6;
+    /// The fewest players the game can be played with
+    pub const MIN_PLAYERS: usize = 2;
+    /// The most players the game can be played with
+    pub const MAX_PLAYERS: usize = 4;
+
+    /// Where the 24 tiles are laid out on the grid, making the island's diamond shape
+    pub const ISLAND_COORDS: [Coord; 24] = [
+        Coord::new(2, 0),
+        Coord::new(3, 0),
+        Coord::new(1, 1),
+        Coord::new(2, 1),
+        Coord::new(3, 1),
+        Coord::new(4, 1),
+        Coord::new(0, 2),
+        Coord::new(1, 2),
+        Coord::new(2, 2),
+        Coord::new(3, 2),
+        Coord::new(4, 2),
+        Coord::new(5, 2),
+        Coord::new(0, 3),
+        Coord::new(1, 3),
+        Coord::new(2, 3),
+        Coord::new(3, 3),
+        Coord::new(4, 3),
+        Coord::new(5, 3),
+        Coord::new(1, 4),
+        Coord::new(2, 4),
+        Coord::new(3, 4),
+        Coord::new(4, 4),
+        Coord::new(2, 5),
+        Coord::new(3, 5),
     ];
 
-    #[derive(Debug)]
+    /// The full state of a game in progress. Changes are made through `apply` and the
+    /// draw methods, which check every move against the rules
+    #[derive(Debug, Clone)]
     pub struct GameBoard {
-        board: [[Option<IslandCard>; 6]; 6],
-        water_level: usize,
-        island_card_locations: HashMap<IslandCardName, (usize, usize)>,
-        pub adventurer_locations: HashMap<AdventurerCardType, (AdventurerCard, (usize, usize))>,
+        board: [[Option<IslandCard>; GRID_SIZE]; GRID_SIZE],
+        water_meter: WaterMeter,
+        island_card_locations: HashMap<IslandCardName, Coord>,
+        adventurer_locations: HashMap<AdventurerCardType, (AdventurerCard, Coord)>,
         treasure_deck: Deck<TreasureCard>,
         treasure_discard_deck: Deck<TreasureCard>,
         flood_deck: Deck<FloodCard>,
         flood_discard_deck: Deck<FloodCard>,
         captured_treasures: HashSet<TreasureType>,
+        turn_order: Vec<AdventurerCardType>,
+        current_turn: usize,
+        turn_number: usize,
+        phase: TurnPhase,
+        actions_left: usize,
+        flood_cards_left: usize,
+        pending_decisions: VecDeque<Decision>,
+        escaped: bool,
+        abilities: AbilityUsage,
+        navigator_rule: NavigatorRule,
     }
 
     impl GameBoard {
-        pub fn new<R>(rng: &mut R, water_level: usize) -> GameBoard
+        /// Sets up a new game for `player_count` adventurers, shuffling the decks with `rng`.
+        /// Panics if `player_count` is not between `MIN_PLAYERS` and `MAX_PLAYERS`
+        pub fn new<R>(rng: &mut R, player_count: usize, difficulty: Difficulty) -> GameBoard
         where
             R: Rng,
         {
-            assert!(water_level <= 4, "Water level cannot start more than 4");
+            assert!(
+                (MIN_PLAYERS..=MAX_PLAYERS).contains(&player_count),
+                "The game is for {MIN_PLAYERS} to {MAX_PLAYERS} players"
+            );
 
             let mut island_deck = IslandCard::get_deck();
             island_deck.shuffle(rng);
@@ -78,32 +140,33 @@ pub mod game_board {
             flood_deck.shuffle(rng);
 
             assert_eq!(island_deck.len(), ISLAND_COORDS.len());
-            let mut board = [[None; 6]; 6];
+            let mut board = [[None; GRID_SIZE]; GRID_SIZE];
             let mut island_card_locations = HashMap::with_capacity(36);
-            let mut adventurer_locations = HashMap::with_capacity(4);
+            let mut adventurer_locations = HashMap::with_capacity(player_count);
 
-            let mut adventurers: Vec<_> = (0..4)
+            let mut adventurers: Vec<_> = (0..player_count)
                 .map(|_| adventurer_deck.pop_next().unwrap())
                 .collect();
+            let turn_order = adventurers.iter().map(|a| a.get_type()).collect();
 
-            for &(x, y) in ISLAND_COORDS.iter() {
+            for &coord in ISLAND_COORDS.iter() {
                 let card = island_deck.pop_next();
                 assert!(card.is_some(), "Missing island cards!");
-                board[y][x] = card;
+                board[coord.y][coord.x] = card;
 
                 let card_name = card.unwrap().name();
-                island_card_locations.insert(card_name, (x, y));
+                island_card_locations.insert(card_name, coord);
 
                 if let Some(index) =
                     (0..adventurers.len()).find(|&i| adventurers[i].get_start_card() == card_name)
                 {
                     let adventurer = adventurers.remove(index);
-                    adventurer_locations.insert(adventurer.get_type(), (adventurer, (x, y)));
+                    adventurer_locations.insert(adventurer.get_type(), (adventurer, coord));
                 }
             }
-            GameBoard {
+            let mut game_board = GameBoard {
                 board,
-                water_level,
+                water_meter: WaterMeter::new(difficulty),
                 island_card_locations,
                 adventurer_locations,
                 treasure_discard_deck: Deck::with_capacity(treasure_deck.len()),
@@ -111,9 +174,424 @@ pub mod game_board {
                 flood_discard_deck: Deck::with_capacity(flood_deck.len()),
                 flood_deck,
                 captured_treasures: HashSet::with_capacity(4),
+                turn_order,
+                current_turn: 0,
+                turn_number: 1,
+                phase: TurnPhase::Actions,
+                actions_left: ACTIONS_PER_TURN,
+                flood_cards_left: 0,
+                pending_decisions: VecDeque::new(),
+                escaped: false,
+                abilities: AbilityUsage::default(),
+                navigator_rule: NavigatorRule::default(),
+            };
+            for _ in 0..STARTING_FLOOD_CARDS {
+                game_board.draw_flood_card(rng);
+            }
+            game_board.deal_starting_hands(rng);
+            game_board
+        }
+
+        /// Deals each adventurer their starting treasure cards. Any Waters Rise! cards drawn
+        /// are set aside and shuffled back into the deck afterwards
+        fn deal_starting_hands<R>(&mut self, rng: &mut R)
+        where
+            R: Rng,
+        {
+            let mut set_aside = Deck::new();
+            for adventurer in self.turn_order.clone() {
+                while self.get_adventurer(&adventurer).get_card_count() < STARTING_HAND_SIZE {
+                    let card = self.treasure_deck.pop_next().unwrap();
+                    if card.get_type() == TreasureCardType::WaterRise {
+                        set_aside.insert(card);
+                    } else {
+                        self.get_adventurer_mut(&adventurer).receive_card(card);
+                    }
+                }
+            }
+            self.treasure_deck.stack(&mut set_aside);
+            self.treasure_deck.shuffle(rng);
+        }
+
+        /// Switches between the ways the Navigator can move other adventurers
+        pub fn set_navigator_rule(&mut self, navigator_rule: NavigatorRule) {
+            self.navigator_rule = navigator_rule;
+        }
+
+        /// Returns the adventurer whose turn it is
+        pub fn current_adventurer(&self) -> AdventurerCardType {
+            self.turn_order[self.current_turn]
+        }
+
+        /// Returns the adventurers in the order they take their turns
+        pub fn turn_order(&self) -> &[AdventurerCardType] {
+            &self.turn_order
+        }
+
+        /// Returns the number of the current turn, starting from 1
+        pub fn turn_number(&self) -> usize {
+            self.turn_number
+        }
+
+        /// Returns the current phase of the turn
+        pub fn phase(&self) -> TurnPhase {
+            self.phase
+        }
+
+        /// Returns the number of actions the current adventurer has left this turn
+        pub fn actions_left(&self) -> usize {
+            self.actions_left
+        }
+
+        /// Returns the decision the game is waiting on, if any
+        pub fn pending_decision(&self) -> Option<Decision> {
+            self.pending_decisions.front().copied()
+        }
+
+        /// Checks the game has not already been won or lost
+        fn expect_in_progress(&self) -> Result<(), TurnError> {
+            match self.outcome() {
+                GameOutcome::InProgress => Ok(()),
+                outcome => Err(TurnError::GameOver(outcome)),
+            }
+        }
+
+        fn expect_phase(&self, expected: TurnPhase) -> Result<(), TurnError> {
+            self.expect_in_progress()?;
+            if let Some(decision) = self.pending_decision() {
+                Err(TurnError::DecisionPending(decision))
+            } else if self.phase == expected {
+                Ok(())
+            } else {
+                Err(TurnError::WrongPhase {
+                    expected,
+                    actual: self.phase,
+                })
+            }
+        }
+
+        fn expect_action(&self) -> Result<(), TurnError> {
+            self.expect_phase(TurnPhase::Actions)?;
+            if self.actions_left == 0 {
+                Err(TurnError::NoActionsLeft)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Uses up one of the current adventurer's actions
+        fn spend_action(&mut self) -> Result<(), TurnError> {
+            self.expect_action()?;
+            self.actions_left -= 1;
+            self.abilities.engineer_shore_up = false;
+            Ok(())
+        }
+
+        /// Ends the action phase, forfeiting any unused actions
+        pub fn end_actions(&mut self) -> Result<(), TurnError> {
+            self.expect_phase(TurnPhase::Actions)?;
+            self.actions_left = 0;
+            self.abilities.engineer_shore_up = false;
+            self.phase = TurnPhase::DrawTreasure;
+            Ok(())
+        }
+
+        /// Draws the current adventurer's treasure cards for the turn.
+        /// Returns the number of Waters Rise! cards drawn
+        pub fn draw_treasure_cards<R>(&mut self, rng: &mut R) -> Result<usize, TurnError>
+        where
+            R: Rng,
+        {
+            self.expect_phase(TurnPhase::DrawTreasure)?;
+            let adventurer = self.current_adventurer();
+            let water_rises = (0..TREASURE_CARDS_PER_TURN)
+                .filter(|_| self.draw_treasure_card(adventurer, rng))
+                .count();
+            self.check_hand_limit(&adventurer);
+            self.phase = TurnPhase::DrawFlood;
+            self.flood_cards_left = self.water_meter.flood_cards();
+            Ok(water_rises)
+        }
+
+        /// Pauses the game on a discard if the adventurer holds too many cards
+        fn check_hand_limit(&mut self, adventurer: &AdventurerCardType) {
+            let decision = Decision::Discard(*adventurer);
+            if self.get_adventurer(adventurer).get_card_count() > HAND_LIMIT
+                && !self.pending_decisions.contains(&decision)
+            {
+                self.pending_decisions.push_back(decision);
             }
         }
 
+        /// Resolves the adventurer's discard once they are back under the hand limit
+        fn resolve_hand_limit(&mut self, adventurer: &AdventurerCardType) {
+            if self.get_adventurer(adventurer).get_card_count() <= HAND_LIMIT {
+                self.pending_decisions
+                    .retain(|decision| decision != &Decision::Discard(*adventurer));
+            }
+        }
+
+        /// Returns the water meter
+        pub fn water_meter(&self) -> &WaterMeter {
+            &self.water_meter
+        }
+
+        /// Returns the treasures captured so far
+        pub fn captured_treasures(&self) -> &HashSet<TreasureType> {
+            &self.captured_treasures
+        }
+
+        /// Returns whether the game has been won, lost or is still being played
+        pub fn outcome(&self) -> GameOutcome {
+            if self.escaped {
+                return GameOutcome::Won;
+            }
+            if self.water_meter.is_deadly() {
+                return GameOutcome::Lost(LossReason::WaterMeterAtSkull);
+            }
+            if self.get_tile(&IslandCardName::FoolsLanding).state() == &IslandCardState::Sunk {
+                return GameOutcome::Lost(LossReason::FoolsLandingSunk);
+            }
+            if let Some(&treasure) = TreasureType::iter().find(|treasure| {
+                !self.captured_treasures.contains(treasure)
+                    && IslandCardName::iter()
+                        .map(|name| self.get_tile(name))
+                        .filter(|tile| tile.can_retrieve(treasure))
+                        .all(|tile| tile.state() == &IslandCardState::Sunk)
+            }) {
+                return GameOutcome::Lost(LossReason::TreasureLost(treasure));
+            }
+            // A Helicopter Lift in anyone's hand can still fly a stranded adventurer away
+            if let Some(&adventurer) = self.turn_order.iter().find(|adventurer| {
+                self.get_adventurer_tile(adventurer).state() == &IslandCardState::Sunk
+                    && self.get_swim_targets(adventurer).is_empty()
+                    && self.get_lift_holders().next().is_none()
+            }) {
+                return GameOutcome::Lost(LossReason::Drowned(adventurer));
+            }
+            GameOutcome::InProgress
+        }
+
+        /// Returns `true` if all four treasures are captured and every adventurer is on
+        /// Fools' Landing, ready to be flown off the island
+        pub fn can_escape(&self) -> bool {
+            self.captured_treasures.len() == TreasureType::all().len()
+                && self.turn_order.iter().all(|adventurer| {
+                    self.get_adventurer_tile(adventurer).name() == IslandCardName::FoolsLanding
+                })
+        }
+
+        /// Draws the flood cards for the turn, then passes play to the next adventurer.
+        /// Stops early if a tile sinks under an adventurer, carrying on with the remaining
+        /// cards when called again once they have swum to safety
+        pub fn draw_flood_cards<R>(&mut self, rng: &mut R) -> Result<(), TurnError>
+        where
+            R: Rng,
+        {
+            self.expect_phase(TurnPhase::DrawFlood)?;
+            while self.flood_cards_left > 0 {
+                self.flood_cards_left -= 1;
+                self.draw_flood_card(rng);
+                if self.pending_decision().is_some() {
+                    return Ok(());
+                }
+            }
+            self.end_turn();
+            Ok(())
+        }
+
+        /// Returns the number of flood cards still to be drawn this turn
+        pub fn flood_cards_left(&self) -> usize {
+            self.flood_cards_left
+        }
+
+        /// Draws a single flood card, flooding its tile or sinking it if already flooded.
+        /// The cards of sunk tiles are removed from the game
+        fn draw_flood_card<R>(&mut self, rng: &mut R)
+        where
+            R: Rng,
+        {
+            if self.flood_deck.is_empty() {
+                self.flood_discard_deck.shuffle(rng);
+                self.flood_deck.stack(&mut self.flood_discard_deck);
+            }
+
+            if let Some(card) = self.flood_deck.pop_next() {
+                let tile = card.island_card();
+                self.sink(&tile);
+                if self.get_tile(&tile).state() != &IslandCardState::Sunk {
+                    self.flood_discard_deck.insert(card);
+                }
+            }
+        }
+
+        fn end_turn(&mut self) {
+            self.current_turn = (self.current_turn + 1) % self.turn_order.len();
+            self.turn_number += 1;
+            self.phase = TurnPhase::Actions;
+            self.actions_left = ACTIONS_PER_TURN;
+            self.abilities = AbilityUsage::default();
+        }
+
+        /// Returns which once per turn abilities have been used this turn
+        pub fn abilities(&self) -> &AbilityUsage {
+            &self.abilities
+        }
+
+        /// Validates `action` against the current state of the game and performs it,
+        /// or returns the reason it is not allowed, leaving the game untouched. Nothing
+        /// is allowed once the game has been won or lost
+        pub fn apply(&mut self, action: Action) -> Result<(), ActionError> {
+            self.expect_in_progress()?;
+            match action {
+                Action::Move(to) => {
+                    self.expect_action()?;
+                    let adventurer = self.current_adventurer();
+                    if !self.get_moves(&adventurer).contains(&to) {
+                        return Err(ActionError::IllegalMove(to));
+                    }
+                    self.spend_action()?;
+                    if adventurer == AdventurerCardType::Pilot
+                        && !self
+                            .get_adventurer_location(&adventurer)
+                            .is_orthogonal_to(&to)
+                    {
+                        self.abilities.pilot_flight = true;
+                    }
+                    self.adventurer_locations
+                        .entry(adventurer)
+                        .and_modify(|(_, pos)| *pos = to);
+                }
+                Action::ShoreUp(tile) => {
+                    // The Engineer's second shore up is free, if it directly follows the first
+                    let free = self.abilities.engineer_shore_up;
+                    if free {
+                        self.expect_phase(TurnPhase::Actions)?;
+                    } else {
+                        self.expect_action()?;
+                    }
+                    let adventurer = self.current_adventurer();
+                    if self.get_tile(&tile).state() != &IslandCardState::Flooded {
+                        return Err(ActionError::NotFlooded(tile));
+                    }
+                    if !self.get_shore_up_targets(&adventurer).contains(&tile) {
+                        return Err(ActionError::OutOfReach(tile));
+                    }
+                    if free {
+                        self.abilities.engineer_shore_up = false;
+                    } else {
+                        self.spend_action()?;
+                        self.abilities.engineer_shore_up =
+                            adventurer == AdventurerCardType::Engineer;
+                    }
+                    self.shore_up(&tile);
+                }
+                Action::Navigate { adventurer, to } => {
+                    self.expect_action()?;
+                    if self.current_adventurer() != AdventurerCardType::Navigator
+                        || adventurer == AdventurerCardType::Navigator
+                    {
+                        return Err(ActionError::NotNavigator);
+                    }
+                    if !self.turn_order.contains(&adventurer) {
+                        return Err(ActionError::NotInGame(adventurer));
+                    }
+                    if !self.get_navigator_moves(&adventurer).contains(&to) {
+                        return Err(ActionError::IllegalMove(to));
+                    }
+                    self.spend_action()?;
+                    self.adventurer_locations
+                        .entry(adventurer)
+                        .and_modify(|(_, pos)| *pos = to);
+                }
+                Action::GiveCard { to, treasure } => {
+                    self.expect_action()?;
+                    let from = self.current_adventurer();
+                    self.check_give(&from, &to, &treasure)?;
+                    self.spend_action()?;
+                    let card = self
+                        .get_adventurer_mut(&from)
+                        .remove_card(&TreasureCardType::Treasure(treasure))
+                        .unwrap();
+                    self.get_adventurer_mut(&to).receive_card(card);
+                    self.check_hand_limit(&to);
+                }
+                Action::CaptureTreasure(treasure) => {
+                    self.expect_action()?;
+                    let adventurer = self.current_adventurer();
+                    self.check_capture(&adventurer, &treasure)?;
+                    self.spend_action()?;
+                    for _ in 0..CARDS_TO_CAPTURE {
+                        let card = self
+                            .get_adventurer_mut(&adventurer)
+                            .remove_card(&TreasureCardType::Treasure(treasure))
+                            .unwrap();
+                        self.treasure_discard_deck.insert(card);
+                    }
+                    self.captured_treasures.insert(treasure);
+                }
+                Action::Pass => self.end_actions()?,
+                Action::PlaySandbag { adventurer, tile } => {
+                    self.check_special_card(&adventurer, &TreasureCardType::Sandbag)?;
+                    if self.get_tile(&tile).state() != &IslandCardState::Flooded {
+                        return Err(ActionError::NotFlooded(tile));
+                    }
+                    self.discard_special_card(&adventurer, &TreasureCardType::Sandbag);
+                    self.shore_up(&tile);
+                }
+                Action::PlayHelicopterLift {
+                    adventurer,
+                    passengers,
+                    to,
+                } => {
+                    self.check_special_card(&adventurer, &TreasureCardType::HelicopterLift)?;
+                    self.check_flight(&passengers, &to)?;
+                    self.discard_special_card(&adventurer, &TreasureCardType::HelicopterLift);
+                    for passenger in passengers.iter() {
+                        self.adventurer_locations
+                            .entry(*passenger)
+                            .and_modify(|(_, pos)| *pos = to);
+                        // Being flown off a sinking tile saves the passenger from swimming
+                        self.pending_decisions
+                            .retain(|decision| decision != &Decision::Swim(*passenger));
+                    }
+                }
+                Action::Escape { adventurer } => {
+                    self.check_special_card(&adventurer, &TreasureCardType::HelicopterLift)?;
+                    if !self.can_escape() {
+                        return Err(ActionError::CannotEscape);
+                    }
+                    self.discard_special_card(&adventurer, &TreasureCardType::HelicopterLift);
+                    self.escaped = true;
+                }
+                Action::Swim { adventurer, to } => {
+                    if self.pending_decision() != Some(Decision::Swim(adventurer)) {
+                        return Err(ActionError::NotSwimming(adventurer));
+                    }
+                    if !self.get_swim_targets(&adventurer).contains(&to) {
+                        return Err(ActionError::IllegalMove(to));
+                    }
+                    self.pending_decisions.pop_front();
+                    self.adventurer_locations
+                        .entry(adventurer)
+                        .and_modify(|(_, pos)| *pos = to);
+                }
+                Action::Discard { adventurer, card } => {
+                    if self.pending_decision() != Some(Decision::Discard(adventurer)) {
+                        return Err(ActionError::NotDiscarding(adventurer));
+                    }
+                    let card = self
+                        .get_adventurer_mut(&adventurer)
+                        .remove_card(&card)
+                        .ok_or(ActionError::CardNotInHand(card))?;
+                    self.treasure_discard_deck.insert(card);
+                    self.resolve_hand_limit(&adventurer);
+                }
+            }
+            Ok(())
+        }
+
+        /// Returns the island drawn as text, one tile per grid square
         pub fn show_board(&self) -> String {
             let mut res = vec![Vec::with_capacity(self.board[0].len()); self.board.len() * 3];
 
@@ -145,44 +623,84 @@ pub mod game_board {
 
             self.adventurer_locations
                 .iter()
-                .map(|(adventurer, &(_, (x, y)))| {
-                    format!("{:?}:  {:?}", adventurer, self.board[y][x].unwrap().name())
+                .map(|(adventurer, (_, coord))| {
+                    format!(
+                        "{:?}:  {:?}",
+                        adventurer,
+                        self.get_card(coord).unwrap().name()
+                    )
                 })
                 .fold(board_string, |acc, val| acc + "\n" + &val)
         }
 
-        pub fn sink(&mut self, card: &IslandCardName) {
-            let (x, y) = *self.island_card_locations.get(card).unwrap();
-            if let Some(card) = &mut self.board[y][x] {
+        /// Floods or sinks the tile. Anyone standing on a tile as it sinks has to swim
+        fn sink(&mut self, card: &IslandCardName) {
+            let coord = self.get_location(card);
+            if let Some(card) = &mut self.board[coord.y][coord.x] {
                 (*card).sink();
+                if card.state() == &IslandCardState::Sunk {
+                    for adventurer in self.turn_order.iter() {
+                        if self.adventurer_locations.get(adventurer).unwrap().1 == coord {
+                            self.pending_decisions
+                                .push_back(Decision::Swim(*adventurer));
+                        }
+                    }
+                }
             }
         }
 
-        pub fn shore_up(&mut self, card: &IslandCardName) {
-            let (x, y) = self.get_location(card);
-            if let Some(card) = &mut self.board[y][x] {
+        fn shore_up(&mut self, card: &IslandCardName) {
+            let coord = self.get_location(card);
+            if let Some(card) = &mut self.board[coord.y][coord.x] {
                 (*card).raise();
             }
         }
 
-        pub fn get_location(&self, card: &IslandCardName) -> (usize, usize) {
+        /// Returns where the tile is on the grid
+        pub fn get_location(&self, card: &IslandCardName) -> Coord {
             *self.island_card_locations.get(card).unwrap()
         }
 
-        pub fn get_card(&self, coord @ &(x, y): &(usize, usize)) -> Option<IslandCard> {
+        /// Returns the tile with the given name
+        pub fn get_tile(&self, card: &IslandCardName) -> IslandCard {
+            self.get_card(&self.get_location(card)).unwrap()
+        }
+
+        /// Returns the adventurer and their hand
+        pub fn get_adventurer(&self, adventurer: &AdventurerCardType) -> &AdventurerCard {
+            &self.adventurer_locations.get(adventurer).unwrap().0
+        }
+
+        fn get_adventurer_mut(&mut self, adventurer: &AdventurerCardType) -> &mut AdventurerCard {
+            &mut self.adventurer_locations.get_mut(adventurer).unwrap().0
+        }
+
+        /// Returns where the adventurer is standing
+        pub fn get_adventurer_location(&self, adventurer: &AdventurerCardType) -> Coord {
+            self.adventurer_locations.get(adventurer).unwrap().1
+        }
+
+        /// Returns the tile the adventurer is standing on
+        pub fn get_adventurer_tile(&self, adventurer: &AdventurerCardType) -> IslandCard {
+            self.get_card(&self.get_adventurer_location(adventurer))
+                .unwrap()
+        }
+
+        /// Returns the tile at `coord`, or `None` if that is not part of the island
+        pub fn get_card(&self, coord: &Coord) -> Option<IslandCard> {
             if ISLAND_COORDS.contains(coord) {
-                self.board[y][x]
+                self.board[coord.y][coord.x]
             } else {
                 None
             }
         }
 
+        /// Returns the tiles orthogonally next to the tile that have not sunk
         pub fn get_adjacent(&self, card: &IslandCardName) -> Vec<IslandCardName> {
-            let (x, y) = self.get_location(card);
-            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
-                .iter()
+            self.get_location(card)
+                .orthogonal()
                 .filter_map(|coord| {
-                    if let Some(card) = self.get_card(coord) {
+                    if let Some(card) = self.get_card(&coord) {
                         if *card.state() != IslandCardState::Sunk {
                             return Some(card.name());
                         }
@@ -192,54 +710,401 @@ pub mod game_board {
                 .collect()
         }
 
-        pub fn get_options(&self, adventurer: &AdventurerCardType, moves_left: usize) {
-            let (adventurer_struct, (x, y)) = self.adventurer_locations.get(adventurer).unwrap();
-            let moves = self.get_moves(adventurer);
+        /// Returns the flooded tiles the adventurer can shore up: their own tile and those
+        /// orthogonally next to it, or diagonally too for the Explorer
+        pub fn get_shore_up_targets(&self, adventurer: &AdventurerCardType) -> Vec<IslandCardName> {
+            let from = self.get_adventurer_location(adventurer);
+            let reach: Vec<_> = match adventurer {
+                AdventurerCardType::Explorer => from.surrounding().collect(),
+                _ => from.orthogonal().collect(),
+            };
+
+            std::iter::once(from)
+                .chain(reach)
+                .filter_map(|coord| self.get_card(&coord))
+                .filter(|tile| tile.state() == &IslandCardState::Flooded)
+                .map(|tile| tile.name())
+                .collect()
+        }
+
+        /// Checks the adventurer is in the game and holds the special card
+        fn check_special_card(
+            &self,
+            adventurer: &AdventurerCardType,
+            card: &TreasureCardType,
+        ) -> Result<(), ActionError> {
+            if !self.turn_order.contains(adventurer) {
+                Err(ActionError::NotInGame(*adventurer))
+            } else if self.get_adventurer(adventurer).count_cards(card) == 0 {
+                Err(ActionError::CardNotInHand(*card))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Moves a played special card from the adventurer's hand to the discard pile
+        fn discard_special_card(
+            &mut self,
+            adventurer: &AdventurerCardType,
+            card: &TreasureCardType,
+        ) {
+            let card = self
+                .get_adventurer_mut(adventurer)
+                .remove_card(card)
+                .unwrap();
+            self.treasure_discard_deck.insert(card);
+            self.resolve_hand_limit(adventurer);
+        }
+
+        /// Checks the passengers share a tile and could be flown from it to `to`
+        fn check_flight(
+            &self,
+            passengers: &[AdventurerCardType],
+            to: &Coord,
+        ) -> Result<(), ActionError> {
+            let from = match passengers.first() {
+                Some(passenger) if self.turn_order.contains(passenger) => {
+                    self.get_adventurer_location(passenger)
+                }
+                Some(passenger) => return Err(ActionError::NotInGame(*passenger)),
+                None => return Err(ActionError::NoPassengers),
+            };
+            for (index, passenger) in passengers.iter().enumerate() {
+                if !self.turn_order.contains(passenger) {
+                    return Err(ActionError::NotInGame(*passenger));
+                }
+                if self.get_adventurer_location(passenger) != from
+                    || passengers[..index].contains(passenger)
+                {
+                    return Err(ActionError::PassengerApart(*passenger));
+                }
+            }
+            match self.get_card(to) {
+                Some(tile) if *to != from && tile.state() != &IslandCardState::Sunk => Ok(()),
+                _ => Err(ActionError::IllegalLanding(*to)),
+            }
+        }
+
+        /// Checks whether `from` could give one of their `treasure` cards to `to`
+        fn check_give(
+            &self,
+            from: &AdventurerCardType,
+            to: &AdventurerCardType,
+            treasure: &TreasureType,
+        ) -> Result<(), ActionError> {
+            let card = TreasureCardType::Treasure(*treasure);
+            if !self.turn_order.contains(to) {
+                Err(ActionError::NotInGame(*to))
+            } else if from == to {
+                Err(ActionError::GiveToSelf)
+            } else if from != &AdventurerCardType::Messenger
+                && self.get_adventurer_location(from) != self.get_adventurer_location(to)
+            {
+                Err(ActionError::NotOnSameTile(*to))
+            } else if self.get_adventurer(from).count_cards(&card) == 0 {
+                Err(ActionError::CardNotInHand(card))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Checks whether the adventurer could capture `treasure` from where they stand
+        fn check_capture(
+            &self,
+            adventurer: &AdventurerCardType,
+            treasure: &TreasureType,
+        ) -> Result<(), ActionError> {
+            if self.captured_treasures.contains(treasure) {
+                Err(ActionError::AlreadyCaptured(*treasure))
+            } else if !self.get_adventurer_tile(adventurer).can_retrieve(treasure) {
+                Err(ActionError::NotOnTreasureTile(*treasure))
+            } else if self
+                .get_adventurer(adventurer)
+                .count_cards(&TreasureCardType::Treasure(*treasure))
+                < CARDS_TO_CAPTURE
+            {
+                Err(ActionError::NotEnoughTreasureCards(*treasure))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Returns every action `adventurer` could legally take with `moves_left` actions
+        /// remaining, capped at the actions actually left this turn. Every returned action
+        /// is accepted by `apply`
+        pub fn get_options(
+            &self,
+            adventurer: &AdventurerCardType,
+            moves_left: usize,
+        ) -> Vec<Action> {
+            let mut options = Vec::new();
+            if let Some(decision) = self.pending_decision() {
+                if decision == Decision::Swim(*adventurer) {
+                    options.extend(self.get_swim_targets(adventurer).into_iter().map(|to| {
+                        Action::Swim {
+                            adventurer: *adventurer,
+                            to,
+                        }
+                    }));
+                    // Anyone else can fly the adventurer to safety instead
+                    for holder in self
+                        .get_lift_holders()
+                        .filter(|holder| holder != adventurer)
+                    {
+                        options.extend(
+                            self.get_helicopter_lift_options(&holder)
+                                .into_iter()
+                                .filter(|action| match action {
+                                    Action::PlayHelicopterLift { passengers, .. } => {
+                                        passengers.contains(adventurer)
+                                    }
+                                    _ => false,
+                                }),
+                        );
+                    }
+                } else if decision == Decision::Discard(*adventurer) {
+                    for card in self.get_adventurer(adventurer).get_hand().iter() {
+                        let discard = Action::Discard {
+                            adventurer: *adventurer,
+                            card: card.get_type(),
+                        };
+                        if !options.contains(&discard) {
+                            options.push(discard);
+                        }
+                    }
+                }
+            } else if self.phase == TurnPhase::Actions && adventurer == &self.current_adventurer() {
+                options
+                    .extend(self.get_turn_options(adventurer, moves_left.min(self.actions_left)));
+            }
 
-            if let Some(treasure_type) = TreasureType::iter().find(|&treasure_type| {
-                adventurer_struct
-                    .get_hand()
+            // Special cards can be played at any time, even on another adventurer's turn
+            let hand = self.get_adventurer(adventurer);
+            if hand.count_cards(&TreasureCardType::HelicopterLift) > 0 {
+                if self.can_escape() {
+                    options.push(Action::Escape {
+                        adventurer: *adventurer,
+                    });
+                }
+                options.extend(self.get_helicopter_lift_options(adventurer));
+            }
+            if hand.count_cards(&TreasureCardType::Sandbag) > 0 {
+                options.extend(
+                    IslandCardName::iter()
+                        .filter(|tile| self.get_tile(tile).state() == &IslandCardState::Flooded)
+                        .map(|&tile| Action::PlaySandbag {
+                            adventurer: *adventurer,
+                            tile,
+                        }),
+                );
+            }
+            options
+        }
+
+        /// Returns the adventurers holding a Helicopter Lift, in turn order
+        fn get_lift_holders(&self) -> impl Iterator<Item = AdventurerCardType> + '_ {
+            self.turn_order.iter().copied().filter(|adventurer| {
+                self.get_adventurer(adventurer)
+                    .count_cards(&TreasureCardType::HelicopterLift)
+                    > 0
+            })
+        }
+
+        /// Returns every flight the adventurer's Helicopter Lift could make: any group of
+        /// adventurers sharing a tile, to any other tile that has not sunk
+        fn get_helicopter_lift_options(&self, adventurer: &AdventurerCardType) -> Vec<Action> {
+            let mut options = Vec::new();
+            let mut groups: Vec<(Coord, Vec<AdventurerCardType>)> = Vec::new();
+            for passenger in self.turn_order.iter() {
+                let from = self.get_adventurer_location(passenger);
+                match groups.iter_mut().find(|(pos, _)| pos == &from) {
+                    Some((_, group)) => group.push(*passenger),
+                    None => groups.push((from, vec![*passenger])),
+                }
+            }
+
+            for (from, group) in groups {
+                let destinations: Vec<_> = ISLAND_COORDS
                     .iter()
-                    .filter(|card| match card.get_type() {
-                        TreasureCardType::Treasure(t) => &t == treasure_type,
-                        _ => false,
+                    .filter(|to| {
+                        **to != from && self.get_card(to).unwrap().state() != &IslandCardState::Sunk
                     })
-                    .count()
-                    >= 4
-                    && self.board[*y][*x].unwrap().can_retrieve(treasure_type)
-            }) {
-                dbg!(treasure_type);
+                    .collect();
+                for subset in 1..(1 << group.len()) {
+                    let passengers: Vec<_> = (0..group.len())
+                        .filter(|index| subset & (1 << index) != 0)
+                        .map(|index| group[index])
+                        .collect();
+                    options.extend(destinations.iter().map(|&&to| Action::PlayHelicopterLift {
+                        adventurer: *adventurer,
+                        passengers: passengers.clone(),
+                        to,
+                    }));
+                }
+            }
+            options
+        }
+
+        /// Returns the actions available to the current adventurer in the action phase
+        fn get_turn_options(
+            &self,
+            adventurer: &AdventurerCardType,
+            moves_left: usize,
+        ) -> Vec<Action> {
+            let mut options = Vec::new();
+            if moves_left > 0 || self.abilities.engineer_shore_up {
+                options.extend(
+                    self.get_shore_up_targets(adventurer)
+                        .into_iter()
+                        .map(Action::ShoreUp),
+                );
+            }
+            if moves_left > 0 {
+                options.extend(self.get_moves(adventurer).into_iter().map(Action::Move));
+                if adventurer == &AdventurerCardType::Navigator {
+                    for other in self.turn_order.iter().filter(|&other| other != adventurer) {
+                        options.extend(self.get_navigator_moves(other).into_iter().map(|to| {
+                            Action::Navigate {
+                                adventurer: *other,
+                                to,
+                            }
+                        }));
+                    }
+                }
+                for to in self.turn_order.iter() {
+                    options.extend(
+                        TreasureType::iter()
+                            .filter(|treasure| self.check_give(adventurer, to, treasure).is_ok())
+                            .map(|&treasure| Action::GiveCard { to: *to, treasure }),
+                    );
+                }
+                options.extend(
+                    TreasureType::iter()
+                        .filter(|treasure| self.check_capture(adventurer, treasure).is_ok())
+                        .copied()
+                        .map(Action::CaptureTreasure),
+                );
+            }
+            options.push(Action::Pass);
+            options
+        }
+
+        /// Returns where the adventurer could move with one action
+        pub fn get_moves(&self, adventurer: &AdventurerCardType) -> Vec<Coord> {
+            self.get_moves_from(adventurer, &self.get_adventurer_location(adventurer))
+        }
+
+        /// Returns where the adventurer could move with one action if standing at `from`
+        pub fn get_moves_from(&self, adventurer: &AdventurerCardType, from: &Coord) -> Vec<Coord> {
+            let reach: Vec<_> = match adventurer {
+                AdventurerCardType::Explorer => from.surrounding().collect(),
+                AdventurerCardType::Diver => return self.get_diver_moves(from),
+                _ => from.orthogonal().collect(),
+            };
+            let mut moves: Vec<_> = reach
+                .into_iter()
+                .filter(|pos| {
+                    self.get_card(pos)
+                        .is_some_and(|tile| tile.state() != &IslandCardState::Sunk)
+                })
+                .collect();
+
+            // Once per turn the Pilot can fly to any tile instead
+            if adventurer == &AdventurerCardType::Pilot && !self.abilities.pilot_flight {
+                for pos in self.get_pilot_flights(from) {
+                    if !moves.contains(&pos) {
+                        moves.push(pos);
+                    }
+                }
             }
+            moves
+        }
+
+        /// Returns every tile that has not sunk, other than `from`
+        fn get_pilot_flights(&self, from: &Coord) -> Vec<Coord> {
+            ISLAND_COORDS
+                .iter()
+                .filter(|&pos| {
+                    pos != from && self.get_card(pos).unwrap().state() != &IslandCardState::Sunk
+                })
+                .copied()
+                .collect()
         }
 
-        pub fn get_moves(&self, adventurer: &AdventurerCardType) -> Vec<(usize, usize)> {
-            let &(_, (x, y)) = self.adventurer_locations.get(adventurer).unwrap();
+        /// Returns every tile the Diver can reach with one action, by searching through
+        /// any number of adjacent flooded or sunk tiles. The Diver has to end on a tile that
+        /// has not sunk. Tiles are ordered by how many tiles away they are
+        fn get_diver_moves(&self, from: &Coord) -> Vec<Coord> {
+            let mut moves = Vec::new();
+            let mut visited = vec![*from];
+            let mut queue = VecDeque::from([*from]);
+
+            while let Some(coord) = queue.pop_front() {
+                for next in coord.orthogonal() {
+                    let Some(tile) = self.get_card(&next) else {
+                        continue;
+                    };
+                    if visited.contains(&next) {
+                        continue;
+                    }
+                    visited.push(next);
+
+                    let state = *tile.state();
+                    if state != IslandCardState::Sunk {
+                        moves.push(next);
+                    }
+                    if state != IslandCardState::Normal {
+                        queue.push_back(next);
+                    }
+                }
+            }
+            moves
+        }
 
+        /// Returns where the adventurer could swim to if their tile sank: anywhere their own
+        /// movement takes them, with the Pilot always able to fly to safety
+        pub fn get_swim_targets(&self, adventurer: &AdventurerCardType) -> Vec<Coord> {
+            let from = self.get_adventurer_location(adventurer);
             match adventurer {
-                AdventurerCardType::Explorer => vec![
-                    (x + 1, y + 1),
-                    (x, y + 1),
-                    (x - 1, y + 1),
-                    (x + 1, y),
-                    (x - 1, y),
-                    (x + 1, y - 1),
-                    (x, y - 1),
-                    (x - 1, y - 1),
-                ],
-                AdventurerCardType::Pilot => Vec::from(ISLAND_COORDS),
-                _ => vec![(x, y + 1), (x + 1, y), (x - 1, y), (x, y - 1)],
-            }
-            .iter()
-            .filter(|&pos @ &(x, y)| {
-                ISLAND_COORDS.contains(pos)
-                    && (self.board[y][x].unwrap().state() == &IslandCardState::Normal
-                        || adventurer == &AdventurerCardType::Diver)
-            })
-            .copied().collect()
+                AdventurerCardType::Pilot => self.get_pilot_flights(&from),
+                _ => self.get_moves_from(adventurer, &from),
+            }
+        }
+
+        /// Returns where the Navigator could move the adventurer to with one action
+        pub fn get_navigator_moves(&self, adventurer: &AdventurerCardType) -> Vec<Coord> {
+            let from = self.get_adventurer_location(adventurer);
+            let mut moves = Vec::new();
+            for step in self.get_navigator_steps(adventurer, &from) {
+                for to in std::iter::once(step).chain(self.get_navigator_steps(adventurer, &step)) {
+                    if to != from
+                        && !moves.contains(&to)
+                        && self.get_card(&to).unwrap().state() != &IslandCardState::Sunk
+                    {
+                        moves.push(to);
+                    }
+                }
+            }
+            moves
         }
 
-        /// Returns `true` if draws a water rise card
-        pub fn draw_treasure_card<R>(&mut self, adventurer: AdventurerCardType, rng: &mut R) -> bool
+        /// Returns where a single step of the Navigator's ability takes the adventurer
+        fn get_navigator_steps(&self, adventurer: &AdventurerCardType, from: &Coord) -> Vec<Coord> {
+            match (self.navigator_rule, adventurer) {
+                (
+                    NavigatorRule::RoleMovement,
+                    AdventurerCardType::Explorer | AdventurerCardType::Diver,
+                ) => self.get_moves_from(adventurer, from),
+                _ => self
+                    .get_adjacent(&self.get_card(from).unwrap().name())
+                    .iter()
+                    .map(|tile| self.get_location(tile))
+                    .collect(),
+            }
+        }
+
+        /// Returns `true` if draws a water rise card, which is resolved and discarded
+        fn draw_treasure_card<R>(&mut self, adventurer: AdventurerCardType, rng: &mut R) -> bool
         where
             R: Rng,
         {
@@ -259,8 +1124,980 @@ pub mod game_board {
                     .and_modify(|(adventurer, _)| adventurer.receive_card(card));
                 false
             } else {
+                self.waters_rise(rng);
+                self.treasure_discard_deck.insert(card);
                 true
             }
         }
+
+        /// Raises the water meter and puts the shuffled flood discard pile on top of the
+        /// flood deck, so the tiles already flooded are drawn again first
+        fn waters_rise<R>(&mut self, rng: &mut R)
+        where
+            R: Rng,
+        {
+            self.water_meter.rise();
+            self.flood_discard_deck.shuffle(rng);
+            self.flood_discard_deck.stack(&mut self.flood_deck);
+            std::mem::swap(&mut self.flood_deck, &mut self.flood_discard_deck);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use rand::SeedableRng;
+        use rand_chacha::ChaChaRng;
+
+        use super::*;
+
+        /// Returns a four player board with empty hands and no flooded tiles
+        fn test_board() -> (GameBoard, ChaChaRng) {
+            let mut rng = ChaChaRng::seed_from_u64(1);
+            let mut game_board = GameBoard::new(&mut rng, 4, Difficulty::Normal);
+            for (adventurer, _) in game_board.adventurer_locations.values_mut() {
+                *adventurer = AdventurerCard::new(&adventurer.get_type());
+            }
+            for tile in IslandCardName::iter() {
+                if game_board.get_tile(tile).state() == &IslandCardState::Flooded {
+                    game_board.shore_up(tile);
+                }
+            }
+            game_board
+                .flood_deck
+                .stack(&mut game_board.flood_discard_deck);
+            (game_board, rng)
+        }
+
+        #[test]
+        fn test_setup() {
+            let mut rng = ChaChaRng::seed_from_u64(1);
+            let game_board = GameBoard::new(&mut rng, 4, Difficulty::Elite);
+
+            let flooded: Vec<_> = IslandCardName::iter()
+                .filter(|tile| game_board.get_tile(tile).state() == &IslandCardState::Flooded)
+                .collect();
+            assert_eq!(flooded.len(), STARTING_FLOOD_CARDS);
+            assert_eq!(game_board.flood_discard_deck.len(), STARTING_FLOOD_CARDS);
+            for card in game_board.flood_discard_deck.iter() {
+                assert!(flooded.contains(&&card.island_card()));
+            }
+            assert_eq!(
+                game_board.flood_deck.len(),
+                IslandCardName::all().len() - STARTING_FLOOD_CARDS
+            );
+
+            assert_eq!(game_board.water_meter().level(), 3);
+            assert_eq!(game_board.phase(), TurnPhase::Actions);
+            assert_eq!(game_board.actions_left(), ACTIONS_PER_TURN);
+            assert_eq!(game_board.turn_number(), 1);
+            assert_eq!(game_board.pending_decision(), None);
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+            assert!(game_board.treasure_discard_deck.is_empty());
+        }
+
+        #[test]
+        fn test_player_count() {
+            for player_count in MIN_PLAYERS..=MAX_PLAYERS {
+                let mut rng = ChaChaRng::seed_from_u64(player_count as u64);
+                let game_board = GameBoard::new(&mut rng, player_count, Difficulty::Normal);
+                assert_eq!(game_board.turn_order().len(), player_count);
+                assert_eq!(game_board.adventurer_locations.len(), player_count);
+
+                for adventurer in game_board.turn_order() {
+                    let hand = game_board.get_adventurer(adventurer);
+                    assert_eq!(hand.get_card_count(), STARTING_HAND_SIZE);
+                    assert_eq!(hand.count_cards(&TreasureCardType::WaterRise), 0);
+                }
+                assert_eq!(
+                    game_board.treasure_deck.len(),
+                    TreasureCardType::all().len() - player_count * STARTING_HAND_SIZE
+                );
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_too_many_players() {
+            GameBoard::new(&mut ChaChaRng::seed_from_u64(1), 5, Difficulty::Normal);
+        }
+
+        #[test]
+        fn test_turn_phases() {
+            let (mut game_board, mut rng) = test_board();
+            let first = game_board.current_adventurer();
+
+            assert_eq!(game_board.phase(), TurnPhase::Actions);
+            for _ in 0..ACTIONS_PER_TURN {
+                assert_eq!(game_board.spend_action(), Ok(()));
+            }
+            assert_eq!(game_board.spend_action(), Err(TurnError::NoActionsLeft));
+
+            game_board.end_actions().unwrap();
+            assert_eq!(game_board.phase(), TurnPhase::DrawTreasure);
+            game_board.draw_treasure_cards(&mut rng).unwrap();
+            assert_eq!(game_board.phase(), TurnPhase::DrawFlood);
+            game_board.draw_flood_cards(&mut rng).unwrap();
+
+            assert_eq!(game_board.phase(), TurnPhase::Actions);
+            assert_eq!(game_board.actions_left(), ACTIONS_PER_TURN);
+            assert_eq!(game_board.turn_number(), 2);
+            assert_ne!(game_board.current_adventurer(), first);
+        }
+
+        #[test]
+        fn test_out_of_phase() {
+            let (mut game_board, mut rng) = test_board();
+
+            assert_eq!(
+                game_board.draw_treasure_cards(&mut rng),
+                Err(TurnError::WrongPhase {
+                    expected: TurnPhase::DrawTreasure,
+                    actual: TurnPhase::Actions
+                })
+            );
+            assert!(game_board.draw_flood_cards(&mut rng).is_err());
+
+            game_board.end_actions().unwrap();
+            assert!(game_board.spend_action().is_err());
+            assert!(game_board.end_actions().is_err());
+        }
+
+        #[test]
+        fn test_flood_cards() {
+            let (mut game_board, mut rng) = test_board();
+            let tile = game_board.flood_deck.peak_next().unwrap().island_card();
+
+            game_board.draw_flood_card(&mut rng);
+            assert_eq!(
+                game_board.get_tile(&tile).state(),
+                &IslandCardState::Flooded
+            );
+            assert_eq!(game_board.flood_discard_deck.len(), 1);
+
+            // Drain the deck so the discard pile, holding only `tile`, is reshuffled onto it
+            game_board.flood_deck = Deck::new();
+            game_board.draw_flood_card(&mut rng);
+            assert_eq!(game_board.get_tile(&tile).state(), &IslandCardState::Sunk);
+            assert!(game_board.flood_deck.is_empty());
+            assert!(game_board.flood_discard_deck.is_empty());
+        }
+
+        #[test]
+        fn test_waters_rise() {
+            let (mut game_board, mut rng) = test_board();
+            for _ in 0..3 {
+                game_board.draw_flood_card(&mut rng);
+            }
+            let discarded: Vec<_> = game_board
+                .flood_discard_deck
+                .iter()
+                .map(|card| card.island_card())
+                .collect();
+            let flood_cards = game_board.flood_deck.len() + discarded.len();
+            game_board
+                .treasure_deck
+                .cards
+                .insert(0, TreasureCard::new(&TreasureCardType::WaterRise));
+
+            let adventurer = game_board.current_adventurer();
+            assert!(game_board.draw_treasure_card(adventurer, &mut rng));
+            assert_eq!(game_board.water_meter().level(), 3);
+            assert_eq!(game_board.get_adventurer(&adventurer).get_card_count(), 0);
+            assert_eq!(
+                game_board
+                    .treasure_discard_deck
+                    .peak_next()
+                    .unwrap()
+                    .get_type(),
+                TreasureCardType::WaterRise
+            );
+
+            assert!(game_board.flood_discard_deck.is_empty());
+            assert_eq!(game_board.flood_deck.len(), flood_cards);
+            for card in game_board.flood_deck.iter().take(discarded.len()) {
+                assert!(discarded.contains(&card.island_card()));
+            }
+        }
+
+        #[test]
+        fn test_outcome() {
+            let (mut game_board, _) = test_board();
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+
+            game_board.sink(&IslandCardName::TempleOfTheSun);
+            game_board.sink(&IslandCardName::TempleOfTheSun);
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+            game_board.sink(&IslandCardName::TempleOfTheMoon);
+            game_board.sink(&IslandCardName::TempleOfTheMoon);
+            assert_eq!(
+                game_board.outcome(),
+                GameOutcome::Lost(LossReason::TreasureLost(TreasureType::Earth))
+            );
+
+            game_board.captured_treasures.insert(TreasureType::Earth);
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+
+            for _ in 0..WaterMeter::SKULL_LEVEL {
+                game_board.water_meter.rise();
+            }
+            assert_eq!(
+                game_board.outcome(),
+                GameOutcome::Lost(LossReason::WaterMeterAtSkull)
+            );
+        }
+
+        #[test]
+        fn test_outcome_won() {
+            let (mut game_board, _) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let escape = Action::Escape { adventurer };
+            game_board
+                .get_adventurer_mut(&adventurer)
+                .receive_card(TreasureCard::new(&TreasureCardType::HelicopterLift));
+            let fools_landing = game_board.get_location(&IslandCardName::FoolsLanding);
+            for (_, (_, pos)) in game_board.adventurer_locations.iter_mut() {
+                *pos = fools_landing;
+            }
+            assert_eq!(
+                game_board.apply(escape.clone()),
+                Err(ActionError::CannotEscape)
+            );
+
+            game_board.captured_treasures.extend(TreasureType::iter());
+            assert!(game_board.get_options(&adventurer, 0).contains(&escape));
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+            game_board.apply(escape).unwrap();
+            assert_eq!(game_board.outcome(), GameOutcome::Won);
+        }
+
+        #[test]
+        fn test_helicopter_lift() {
+            let (mut game_board, _) = test_board();
+            let [first, second, third, ..] = game_board.turn_order()[..] else {
+                unreachable!()
+            };
+            let from = game_board.get_adventurer_location(&first);
+            game_board
+                .adventurer_locations
+                .entry(second)
+                .and_modify(|(_, pos)| *pos = from);
+            game_board
+                .get_adventurer_mut(&third)
+                .receive_card(TreasureCard::new(&TreasureCardType::HelicopterLift));
+            let to = game_board.get_location(&IslandCardName::Watchtower);
+
+            assert_eq!(
+                game_board.apply(Action::PlayHelicopterLift {
+                    adventurer: third,
+                    passengers: vec![first, third],
+                    to
+                }),
+                Err(ActionError::PassengerApart(third))
+            );
+            game_board.sink(&IslandCardName::Watchtower);
+            game_board.sink(&IslandCardName::Watchtower);
+            assert_eq!(
+                game_board.apply(Action::PlayHelicopterLift {
+                    adventurer: third,
+                    passengers: vec![first, second],
+                    to
+                }),
+                Err(ActionError::IllegalLanding(to))
+            );
+
+            let to = game_board.get_location(&IslandCardName::CoralPalace);
+            let lift = Action::PlayHelicopterLift {
+                adventurer: third,
+                passengers: vec![first, second],
+                to,
+            };
+            assert!(game_board.get_options(&third, 0).contains(&lift));
+            game_board.apply(lift).unwrap();
+            assert_eq!(game_board.get_adventurer_location(&first), to);
+            assert_eq!(game_board.get_adventurer_location(&second), to);
+            assert_eq!(game_board.get_adventurer(&third).get_card_count(), 0);
+        }
+
+        #[test]
+        fn test_capture_treasure() {
+            let (mut game_board, _) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let temple = game_board.get_location(&IslandCardName::TempleOfTheSun);
+            let earth = TreasureCardType::Treasure(TreasureType::Earth);
+            for _ in 0..CARDS_TO_CAPTURE {
+                game_board
+                    .get_adventurer_mut(&adventurer)
+                    .receive_card(TreasureCard::new(&earth));
+            }
+
+            assert_eq!(
+                game_board.apply(Action::CaptureTreasure(TreasureType::Earth)),
+                Err(ActionError::NotOnTreasureTile(TreasureType::Earth))
+            );
+            game_board
+                .adventurer_locations
+                .entry(adventurer)
+                .and_modify(|(_, pos)| *pos = temple);
+            assert_eq!(
+                game_board.apply(Action::CaptureTreasure(TreasureType::Fire)),
+                Err(ActionError::NotOnTreasureTile(TreasureType::Fire))
+            );
+            assert!(game_board
+                .get_options(&adventurer, 1)
+                .contains(&Action::CaptureTreasure(TreasureType::Earth)));
+
+            game_board
+                .apply(Action::CaptureTreasure(TreasureType::Earth))
+                .unwrap();
+            assert!(game_board
+                .captured_treasures()
+                .contains(&TreasureType::Earth));
+            assert_eq!(
+                game_board.get_adventurer(&adventurer).count_cards(&earth),
+                0
+            );
+            assert_eq!(game_board.treasure_discard_deck.len(), CARDS_TO_CAPTURE);
+            assert_eq!(
+                game_board.apply(Action::CaptureTreasure(TreasureType::Earth)),
+                Err(ActionError::AlreadyCaptured(TreasureType::Earth))
+            );
+        }
+
+        #[test]
+        fn test_hand_limit() {
+            let (mut game_board, mut rng) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let earth = TreasureCardType::Treasure(TreasureType::Earth);
+            for _ in 0..HAND_LIMIT {
+                game_board
+                    .get_adventurer_mut(&adventurer)
+                    .receive_card(TreasureCard::new(&earth));
+            }
+            game_board.treasure_deck = deck![
+                TreasureCard::new(&TreasureCardType::Sandbag),
+                TreasureCard::new(&earth),
+                TreasureCard::new(&earth),
+            ];
+
+            game_board.apply(Action::Pass).unwrap();
+            game_board.draw_treasure_cards(&mut rng).unwrap();
+            let decision = Decision::Discard(adventurer);
+            assert_eq!(game_board.pending_decision(), Some(decision));
+            assert_eq!(
+                game_board.draw_flood_cards(&mut rng),
+                Err(TurnError::DecisionPending(decision))
+            );
+
+            let options = game_board.get_options(&adventurer, 0);
+            assert_eq!(options.len(), 2);
+            let sandbag = Action::Discard {
+                adventurer,
+                card: TreasureCardType::Sandbag,
+            };
+            assert!(options.contains(&sandbag));
+
+            game_board.apply(sandbag.clone()).unwrap();
+            assert_eq!(game_board.pending_decision(), Some(decision));
+            assert_eq!(
+                game_board.apply(sandbag),
+                Err(ActionError::CardNotInHand(TreasureCardType::Sandbag))
+            );
+            game_board
+                .apply(Action::Discard {
+                    adventurer,
+                    card: earth,
+                })
+                .unwrap();
+            assert_eq!(game_board.pending_decision(), None);
+            assert_eq!(
+                game_board.get_adventurer(&adventurer).get_card_count(),
+                HAND_LIMIT
+            );
+            game_board.draw_flood_cards(&mut rng).unwrap();
+        }
+
+        #[test]
+        fn test_give_card() {
+            let (mut game_board, _) = test_board();
+            let from = game_board.current_adventurer();
+            let to = *game_board
+                .turn_order()
+                .iter()
+                .find(|&&adventurer| {
+                    adventurer != from && adventurer != AdventurerCardType::Messenger
+                })
+                .unwrap();
+            let fire = TreasureCardType::Treasure(TreasureType::Fire);
+            game_board
+                .get_adventurer_mut(&from)
+                .receive_card(TreasureCard::new(&fire));
+            let give = Action::GiveCard {
+                to,
+                treasure: TreasureType::Fire,
+            };
+
+            if from != AdventurerCardType::Messenger {
+                assert_eq!(
+                    game_board.apply(give.clone()),
+                    Err(ActionError::NotOnSameTile(to))
+                );
+                let pos = game_board.get_adventurer_location(&to);
+                game_board
+                    .adventurer_locations
+                    .entry(from)
+                    .and_modify(|(_, from_pos)| *from_pos = pos);
+            }
+            assert!(game_board.get_options(&from, 1).contains(&give));
+            assert_eq!(
+                game_board.apply(Action::GiveCard {
+                    to: from,
+                    treasure: TreasureType::Fire
+                }),
+                Err(ActionError::GiveToSelf)
+            );
+
+            game_board.apply(give.clone()).unwrap();
+            assert_eq!(game_board.get_adventurer(&from).count_cards(&fire), 0);
+            assert_eq!(game_board.get_adventurer(&to).count_cards(&fire), 1);
+            assert_eq!(game_board.actions_left(), ACTIONS_PER_TURN - 1);
+            assert_eq!(
+                game_board.apply(give),
+                Err(ActionError::CardNotInHand(fire))
+            );
+        }
+
+        #[test]
+        fn test_messenger_gives_anywhere() {
+            let (mut game_board, _) = test_board();
+            let messenger = AdventurerCardType::Messenger;
+            let to = game_board.turn_order()[1];
+            game_board.turn_order[0] = messenger;
+            game_board.adventurer_locations.insert(
+                messenger,
+                (AdventurerCard::new(&messenger), Coord::new(2, 0)),
+            );
+            game_board
+                .get_adventurer_mut(&messenger)
+                .receive_card(TreasureCard::new(&TreasureCardType::Treasure(
+                    TreasureType::Ocean,
+                )));
+
+            assert_ne!(
+                game_board.get_adventurer_location(&to),
+                game_board.get_adventurer_location(&messenger)
+            );
+            game_board
+                .apply(Action::GiveCard {
+                    to,
+                    treasure: TreasureType::Ocean,
+                })
+                .unwrap();
+        }
+
+        #[test]
+        fn test_play_sandbag() {
+            let (mut game_board, mut rng) = test_board();
+            let adventurer = game_board.turn_order()[1];
+            let tile = IslandCardName::CaveOfShadows;
+            let play = Action::PlaySandbag { adventurer, tile };
+            game_board.sink(&tile);
+
+            assert_eq!(
+                game_board.apply(play.clone()),
+                Err(ActionError::CardNotInHand(TreasureCardType::Sandbag))
+            );
+            game_board
+                .get_adventurer_mut(&adventurer)
+                .receive_card(TreasureCard::new(&TreasureCardType::Sandbag));
+            assert!(game_board.get_options(&adventurer, 0).contains(&play));
+
+            // Playable out of turn and outside of the action phase
+            game_board.apply(Action::Pass).unwrap();
+            game_board.draw_treasure_cards(&mut rng).unwrap();
+            game_board.apply(play.clone()).unwrap();
+            assert_eq!(game_board.get_tile(&tile).state(), &IslandCardState::Normal);
+            assert_eq!(game_board.get_adventurer(&adventurer).get_card_count(), 0);
+            assert!(game_board
+                .treasure_discard_deck
+                .iter()
+                .any(|card| card.get_type() == TreasureCardType::Sandbag));
+        }
+
+        #[test]
+        fn test_engineer_shore_up() {
+            let (mut game_board, _) = test_board();
+            let engineer = AdventurerCardType::Engineer;
+            game_board.turn_order[0] = engineer;
+            let bronze_gate = game_board.get_location(&IslandCardName::BronzeGate);
+            game_board
+                .adventurer_locations
+                .insert(engineer, (AdventurerCard::new(&engineer), bronze_gate));
+            let mut reach = game_board.get_adjacent(&IslandCardName::BronzeGate);
+            reach.push(IslandCardName::BronzeGate);
+            assert!(reach.len() >= 3);
+            reach.truncate(3);
+            for tile in reach.iter() {
+                game_board.sink(tile);
+            }
+
+            // Two shore ups for a single action
+            game_board.apply(Action::ShoreUp(reach[0])).unwrap();
+            game_board.apply(Action::ShoreUp(reach[1])).unwrap();
+            assert_eq!(game_board.actions_left(), ACTIONS_PER_TURN - 1);
+
+            // Anything in between uses up the second shore up
+            game_board.sink(&reach[0]);
+            game_board.sink(&reach[1]);
+            game_board.apply(Action::ShoreUp(reach[0])).unwrap();
+            let to = game_board.get_moves(&engineer)[0];
+            game_board.apply(Action::Move(to)).unwrap();
+            assert_eq!(game_board.actions_left(), 0);
+            assert_eq!(
+                game_board.apply(Action::ShoreUp(reach[1])),
+                Err(ActionError::Turn(TurnError::NoActionsLeft))
+            );
+        }
+
+        #[test]
+        fn test_navigator_moves() {
+            let (mut game_board, _) = test_board();
+            let navigator = AdventurerCardType::Navigator;
+            let explorer = AdventurerCardType::Explorer;
+            game_board.turn_order = vec![navigator, explorer];
+            game_board.adventurer_locations = HashMap::from([
+                (
+                    navigator,
+                    (AdventurerCard::new(&navigator), Coord::new(2, 2)),
+                ),
+                (explorer, (AdventurerCard::new(&explorer), Coord::new(2, 2))),
+            ]);
+
+            // Two diagonal steps
+            let moves = game_board.get_navigator_moves(&explorer);
+            assert!(moves.contains(&Coord::new(4, 4)));
+            assert!(!moves.contains(&Coord::new(2, 2)));
+
+            game_board.set_navigator_rule(NavigatorRule::PlainAdjacency);
+            let moves = game_board.get_navigator_moves(&explorer);
+            assert!(!moves.contains(&Coord::new(4, 4)));
+            assert!(moves.contains(&Coord::new(3, 3)));
+            assert!(moves.contains(&Coord::new(0, 2)));
+
+            let navigate = Action::Navigate {
+                adventurer: explorer,
+                to: Coord::new(2, 4),
+            };
+            assert!(game_board.get_options(&navigator, 1).contains(&navigate));
+            game_board.apply(navigate).unwrap();
+            assert_eq!(
+                game_board.get_adventurer_location(&explorer),
+                Coord::new(2, 4)
+            );
+            assert_eq!(
+                game_board.apply(Action::Navigate {
+                    adventurer: navigator,
+                    to: Coord::new(2, 3)
+                }),
+                Err(ActionError::NotNavigator)
+            );
+        }
+
+        #[test]
+        fn test_diver_moves() {
+            let (mut game_board, _) = test_board();
+            let diver = AdventurerCardType::Diver;
+            game_board.turn_order = vec![diver];
+            game_board.adventurer_locations =
+                HashMap::from([(diver, (AdventurerCard::new(&diver), Coord::new(1, 2)))]);
+            let tile_at = |game_board: &GameBoard, pos| game_board.get_card(&pos).unwrap().name();
+
+            // Normal tiles block the Diver like anyone else
+            let mut moves = game_board.get_moves(&diver);
+            moves.sort();
+            assert_eq!(
+                moves,
+                vec![
+                    Coord::new(0, 2),
+                    Coord::new(1, 1),
+                    Coord::new(1, 3),
+                    Coord::new(2, 2)
+                ]
+            );
+
+            // Swim through a sunk tile and a flooded tile, but never end on a sunk tile
+            let sunk = tile_at(&game_board, Coord::new(2, 2));
+            game_board.sink(&sunk);
+            game_board.sink(&sunk);
+            game_board.sink(&tile_at(&game_board, Coord::new(3, 2)));
+            let moves = game_board.get_moves(&diver);
+            assert!(!moves.contains(&Coord::new(2, 2)));
+            for pos in [
+                Coord::new(3, 2),
+                Coord::new(2, 1),
+                Coord::new(2, 3),
+                Coord::new(4, 2),
+                Coord::new(3, 1),
+                Coord::new(3, 3),
+            ] {
+                assert!(moves.contains(&pos), "Diver cannot reach {pos:?}");
+            }
+            assert!(!moves.contains(&Coord::new(5, 2)));
+            assert!(!moves.contains(&Coord::new(2, 0)));
+        }
+
+        #[test]
+        fn test_pilot_flight() {
+            let (mut game_board, mut rng) = test_board();
+            let pilot = AdventurerCardType::Pilot;
+            game_board.turn_order = vec![pilot];
+            game_board.adventurer_locations =
+                HashMap::from([(pilot, (AdventurerCard::new(&pilot), Coord::new(2, 2)))]);
+            let sunk = game_board.get_card(&Coord::new(5, 3)).unwrap().name();
+            game_board.sink(&sunk);
+            game_board.sink(&sunk);
+
+            let moves = game_board.get_moves(&pilot);
+            assert_eq!(moves.len(), ISLAND_COORDS.len() - 2);
+            assert!(!moves.contains(&Coord::new(5, 3)));
+
+            // Walking to an adjacent tile does not use up the flight
+            game_board.apply(Action::Move(Coord::new(2, 3))).unwrap();
+            assert!(!game_board.abilities().pilot_flight);
+            game_board.apply(Action::Move(Coord::new(5, 2))).unwrap();
+            assert!(game_board.abilities().pilot_flight);
+            assert_eq!(game_board.get_moves(&pilot), vec![Coord::new(4, 2)]);
+            assert_eq!(
+                game_board.apply(Action::Move(Coord::new(1, 2))),
+                Err(ActionError::IllegalMove(Coord::new(1, 2)))
+            );
+
+            game_board.apply(Action::Pass).unwrap();
+            game_board.draw_treasure_cards(&mut rng).unwrap();
+            game_board.pending_decisions.clear();
+            game_board.draw_flood_cards(&mut rng).unwrap();
+            assert!(!game_board.abilities().pilot_flight);
+        }
+
+        #[test]
+        fn test_shore_up_targets() {
+            let (mut game_board, _) = test_board();
+            let explorer = AdventurerCardType::Explorer;
+            let diver = AdventurerCardType::Diver;
+            game_board.turn_order = vec![explorer, diver];
+            game_board.adventurer_locations = HashMap::from([
+                (explorer, (AdventurerCard::new(&explorer), Coord::new(2, 2))),
+                (diver, (AdventurerCard::new(&diver), Coord::new(2, 2))),
+            ]);
+            for pos in [
+                Coord::new(2, 2),
+                Coord::new(3, 2),
+                Coord::new(3, 3),
+                Coord::new(4, 4),
+            ] {
+                game_board.sink(&game_board.get_card(&pos).unwrap().name());
+            }
+            let sunk = game_board.get_card(&Coord::new(1, 1)).unwrap().name();
+            game_board.sink(&sunk);
+            game_board.sink(&sunk);
+
+            let tile_at = |pos| game_board.get_card(&pos).unwrap().name();
+            assert_eq!(
+                game_board.get_shore_up_targets(&diver),
+                vec![tile_at(Coord::new(2, 2)), tile_at(Coord::new(3, 2))]
+            );
+            assert_eq!(
+                game_board.get_shore_up_targets(&explorer),
+                vec![
+                    tile_at(Coord::new(2, 2)),
+                    tile_at(Coord::new(3, 2)),
+                    tile_at(Coord::new(3, 3))
+                ]
+            );
+        }
+
+        #[test]
+        fn test_swim_to_safety() {
+            let (mut game_board, mut rng) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let tile = game_board.get_adventurer_tile(&adventurer).name();
+            game_board.flood_deck = deck![
+                FloodCard::new(tile),
+                FloodCard::new(IslandCardName::Observatory),
+            ];
+            game_board.sink(&tile);
+            game_board.apply(Action::Pass).unwrap();
+            game_board.treasure_deck = deck![
+                TreasureCard::new(&TreasureCardType::Sandbag),
+                TreasureCard::new(&TreasureCardType::Sandbag),
+                TreasureCard::new(&TreasureCardType::Sandbag),
+            ];
+            game_board.draw_treasure_cards(&mut rng).unwrap();
+
+            // The flood phase stops as soon as the tile sinks
+            game_board.draw_flood_cards(&mut rng).unwrap();
+            let decision = Decision::Swim(adventurer);
+            assert_eq!(game_board.pending_decision(), Some(decision));
+            assert_eq!(game_board.flood_cards_left(), 1);
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+            assert_eq!(
+                game_board.draw_flood_cards(&mut rng),
+                Err(TurnError::DecisionPending(decision))
+            );
+
+            let options = game_board.get_options(&adventurer, 0);
+            let Some(Action::Swim { to, .. }) = options.first().cloned() else {
+                panic!("{adventurer:?} has nowhere to swim")
+            };
+            assert_eq!(
+                game_board.apply(Action::Swim {
+                    adventurer,
+                    to: game_board.get_location(&tile)
+                }),
+                Err(ActionError::IllegalMove(game_board.get_location(&tile)))
+            );
+            game_board.apply(Action::Swim { adventurer, to }).unwrap();
+            assert_eq!(game_board.pending_decision(), None);
+            assert_eq!(game_board.get_adventurer_location(&adventurer), to);
+
+            game_board.draw_flood_cards(&mut rng).unwrap();
+            assert_eq!(
+                game_board.get_tile(&IslandCardName::Observatory).state(),
+                &IslandCardState::Flooded
+            );
+            assert_eq!(game_board.phase(), TurnPhase::Actions);
+        }
+
+        #[test]
+        fn test_helicopter_lift_instead_of_swim() {
+            let (mut game_board, mut rng) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let tile = game_board.get_adventurer_tile(&adventurer).name();
+            game_board.flood_deck = deck![
+                FloodCard::new(tile),
+                FloodCard::new(IslandCardName::Observatory),
+            ];
+            game_board.sink(&tile);
+            game_board.apply(Action::Pass).unwrap();
+            game_board.treasure_deck = deck![
+                TreasureCard::new(&TreasureCardType::Sandbag),
+                TreasureCard::new(&TreasureCardType::Sandbag),
+                TreasureCard::new(&TreasureCardType::Sandbag),
+            ];
+            game_board.draw_treasure_cards(&mut rng).unwrap();
+            game_board.draw_flood_cards(&mut rng).unwrap();
+            assert_eq!(
+                game_board.pending_decision(),
+                Some(Decision::Swim(adventurer))
+            );
+
+            game_board
+                .get_adventurer_mut(&adventurer)
+                .receive_card(TreasureCard::new(&TreasureCardType::HelicopterLift));
+            let to = game_board.get_location(&IslandCardName::FoolsLanding);
+            game_board
+                .apply(Action::PlayHelicopterLift {
+                    adventurer,
+                    passengers: vec![adventurer],
+                    to,
+                })
+                .unwrap();
+            assert_eq!(game_board.get_adventurer_location(&adventurer), to);
+            assert_eq!(game_board.pending_decision(), None);
+
+            game_board.draw_flood_cards(&mut rng).unwrap();
+            assert_eq!(
+                game_board.get_tile(&IslandCardName::Observatory).state(),
+                &IslandCardState::Flooded
+            );
+            assert_eq!(game_board.phase(), TurnPhase::Actions);
+        }
+
+        #[test]
+        fn test_drowned() {
+            let (mut game_board, _) = test_board();
+            let engineer = AdventurerCardType::Engineer;
+            let fools_landing = game_board.get_location(&IslandCardName::FoolsLanding);
+            let &start = ISLAND_COORDS
+                .iter()
+                .find(|coord| {
+                    **coord != fools_landing && coord.surrounding().all(|c| c != fools_landing)
+                })
+                .unwrap();
+            game_board.turn_order = vec![engineer];
+            game_board.adventurer_locations =
+                HashMap::from([(engineer, (AdventurerCard::new(&engineer), start))]);
+            game_board.captured_treasures.extend(TreasureType::iter());
+
+            for pos in std::iter::once(start).chain(start.orthogonal()) {
+                if let Some(tile) = game_board.get_card(&pos) {
+                    game_board.sink(&tile.name());
+                    game_board.sink(&tile.name());
+                }
+            }
+            assert_eq!(
+                game_board.pending_decision(),
+                Some(Decision::Swim(engineer))
+            );
+            assert!(game_board.get_options(&engineer, 0).is_empty());
+            assert_eq!(
+                game_board.outcome(),
+                GameOutcome::Lost(LossReason::Drowned(engineer))
+            );
+        }
+
+        #[test]
+        fn test_game_over() {
+            let (mut game_board, mut rng) = test_board();
+            game_board.sink(&IslandCardName::FoolsLanding);
+            game_board.sink(&IslandCardName::FoolsLanding);
+            let outcome = GameOutcome::Lost(LossReason::FoolsLandingSunk);
+            assert_eq!(game_board.outcome(), outcome);
+
+            let to = game_board.get_moves(&game_board.current_adventurer())[0];
+            assert_eq!(
+                game_board.apply(Action::Move(to)),
+                Err(ActionError::Turn(TurnError::GameOver(outcome)))
+            );
+            assert_eq!(game_board.end_actions(), Err(TurnError::GameOver(outcome)));
+            game_board.phase = TurnPhase::DrawTreasure;
+            assert_eq!(
+                game_board.draw_treasure_cards(&mut rng),
+                Err(TurnError::GameOver(outcome))
+            );
+            game_board.phase = TurnPhase::DrawFlood;
+            assert_eq!(
+                game_board.draw_flood_cards(&mut rng),
+                Err(TurnError::GameOver(outcome))
+            );
+        }
+
+        #[test]
+        fn test_lifted_instead_of_drowning() {
+            let (mut game_board, _) = test_board();
+            let engineer = AdventurerCardType::Engineer;
+            let diver = AdventurerCardType::Diver;
+            let fools_landing = game_board.get_location(&IslandCardName::FoolsLanding);
+            let &start = ISLAND_COORDS
+                .iter()
+                .find(|coord| {
+                    **coord != fools_landing && coord.surrounding().all(|c| c != fools_landing)
+                })
+                .unwrap();
+            let mut diver_card = AdventurerCard::new(&diver);
+            diver_card.receive_card(TreasureCard::new(&TreasureCardType::HelicopterLift));
+            game_board.turn_order = vec![engineer, diver];
+            game_board.adventurer_locations = HashMap::from([
+                (engineer, (AdventurerCard::new(&engineer), start)),
+                (diver, (diver_card, fools_landing)),
+            ]);
+
+            for pos in std::iter::once(start).chain(start.orthogonal()) {
+                if let Some(tile) = game_board.get_card(&pos) {
+                    game_board.sink(&tile.name());
+                    game_board.sink(&tile.name());
+                }
+            }
+            assert_eq!(
+                game_board.pending_decision(),
+                Some(Decision::Swim(engineer))
+            );
+            assert!(game_board.get_swim_targets(&engineer).is_empty());
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+
+            let lift = Action::PlayHelicopterLift {
+                adventurer: diver,
+                passengers: vec![engineer],
+                to: fools_landing,
+            };
+            let options = game_board.get_options(&engineer, 0);
+            assert!(options.contains(&lift));
+            assert!(options.iter().all(|action| match action {
+                Action::PlayHelicopterLift { passengers, .. } => passengers.contains(&engineer),
+                _ => false,
+            }));
+            game_board.apply(lift).unwrap();
+            assert_eq!(game_board.get_adventurer_location(&engineer), fools_landing);
+            assert_eq!(game_board.pending_decision(), None);
+            assert_eq!(game_board.outcome(), GameOutcome::InProgress);
+        }
+
+        #[test]
+        fn test_options_capped_by_actions_left() {
+            let (mut game_board, _) = test_board();
+            let adventurer = game_board.current_adventurer();
+            for _ in 0..ACTIONS_PER_TURN {
+                game_board.spend_action().unwrap();
+            }
+            let options = game_board.get_options(&adventurer, ACTIONS_PER_TURN);
+            assert_eq!(options, vec![Action::Pass]);
+            for action in options {
+                game_board.clone().apply(action).unwrap();
+            }
+        }
+
+        #[test]
+        fn test_apply_move() {
+            let (mut game_board, _) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let start = game_board.get_adventurer_location(&adventurer);
+            let to = game_board.get_moves(&adventurer)[0];
+
+            assert_eq!(
+                game_board.apply(Action::Move(Coord::new(9, 9))),
+                Err(ActionError::IllegalMove(Coord::new(9, 9)))
+            );
+            assert_eq!(game_board.get_adventurer_location(&adventurer), start);
+            assert_eq!(game_board.actions_left(), ACTIONS_PER_TURN);
+
+            game_board.apply(Action::Move(to)).unwrap();
+            assert_eq!(game_board.get_adventurer_location(&adventurer), to);
+            assert_eq!(game_board.actions_left(), ACTIONS_PER_TURN - 1);
+        }
+
+        #[test]
+        fn test_options_are_legal() {
+            let (mut game_board, _) = test_board();
+            let adventurer = game_board.current_adventurer();
+            let tile = game_board.get_adventurer_tile(&adventurer).name();
+            game_board.sink(&tile);
+
+            let options = game_board.get_options(&adventurer, game_board.actions_left());
+            assert!(options.contains(&Action::ShoreUp(tile)));
+            assert!(options.contains(&Action::Pass));
+            for option in options {
+                let mut copy = game_board.clone();
+                assert_eq!(copy.apply(option), Ok(()));
+            }
+
+            assert_eq!(game_board.get_options(&adventurer, 0), vec![Action::Pass]);
+            let other = game_board.turn_order()[1];
+            assert!(game_board.get_options(&other, 3).is_empty());
+        }
+
+        #[test]
+        fn test_apply_shore_up() {
+            let (mut game_board, _) = test_board();
+            let tile = game_board
+                .get_adventurer_tile(&game_board.current_adventurer())
+                .name();
+
+            assert_eq!(
+                game_board.apply(Action::ShoreUp(tile)),
+                Err(ActionError::NotFlooded(tile))
+            );
+
+            game_board.sink(&tile);
+            game_board.apply(Action::ShoreUp(tile)).unwrap();
+            assert_eq!(game_board.get_tile(&tile).state(), &IslandCardState::Normal);
+
+            game_board.apply(Action::Pass).unwrap();
+            assert_eq!(
+                game_board.apply(Action::ShoreUp(tile)),
+                Err(ActionError::Turn(TurnError::WrongPhase {
+                    expected: TurnPhase::Actions,
+                    actual: TurnPhase::DrawTreasure
+                }))
+            );
+        }
     }
 }
@@ -0,0 +1,25 @@
+use super::cards::{adventurer::AdventurerCardType, treasure::TreasureType};
+
+/// Why a game was lost
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LossReason {
+    /// Both tiles the treasure can be captured from sank before it was captured
+    TreasureLost(TreasureType),
+    /// Fools' Landing sank, leaving no way off the island
+    FoolsLandingSunk,
+    /// The adventurer's tile sank and there was nowhere to swim to
+    Drowned(AdventurerCardType),
+    /// The water meter reached the skull and crossbones
+    WaterMeterAtSkull,
+}
+
+/// Whether a game is still being played, or how it ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameOutcome {
+    /// The game has not ended yet
+    InProgress,
+    /// Everyone escaped with all four treasures
+    Won,
+    /// The game was lost, and why
+    Lost(LossReason),
+}
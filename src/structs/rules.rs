@@ -0,0 +1,10 @@
+/// How far the Navigator can move other adventurers with one action
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NavigatorRule {
+    /// Up to two steps of the adventurer's own movement, so the Explorer can move
+    /// diagonally and the Diver can swim through flooded and sunk tiles
+    #[default]
+    RoleMovement,
+    /// Up to two orthogonally adjacent tiles, whoever is being moved
+    PlainAdjacency,
+}
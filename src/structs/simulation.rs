@@ -0,0 +1,483 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+use super::{
+    action::Action,
+    agent::Agent,
+    cards::adventurer::AdventurerCardType,
+    game_board::GameBoard,
+    outcome::{GameOutcome, LossReason},
+    turn::{Decision, TurnPhase},
+    water_meter::Difficulty,
+};
+
+/// The z-score of a two sided 95% confidence interval
+const Z_95: f64 = 1.96;
+/// How many games a worker thread claims at a time
+const GAMES_PER_CLAIM: u64 = 16;
+
+/// The settings shared by every game in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// The seed every game's `ChaChaRng` is derived from, see `game_rng`
+    pub seed: u64,
+    /// The number of games to play
+    pub games: u64,
+    /// The number of adventurers in each game
+    pub player_count: usize,
+    /// The difficulty every game is played at
+    pub difficulty: Difficulty,
+    /// The number of worker threads to spread the games across. The report is the same
+    /// whatever the number of threads
+    pub threads: usize,
+}
+
+/// How a single game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameRecord {
+    /// How the game ended
+    pub outcome: GameOutcome,
+    /// The turn the game ended on, starting from 1
+    pub turns: usize,
+}
+
+/// Plays the game through to the end, with `agent` making every decision.
+/// Panics if the agent picks an action that is not allowed
+pub fn play_game<R>(game_board: &mut GameBoard, agent: &mut dyn Agent, rng: &mut R) -> GameRecord
+where
+    R: Rng,
+{
+    while game_board.outcome() == GameOutcome::InProgress {
+        if offer_special_cards(game_board, agent) {
+            continue;
+        }
+        if let Some(decision) = game_board.pending_decision() {
+            let adventurer = decision.adventurer();
+            let options = game_board.get_options(&adventurer, game_board.actions_left());
+            let action = match decision {
+                Decision::Discard(_) => agent.choose_discard(game_board, &adventurer, &options),
+                Decision::Swim(_) => agent.choose_swim(game_board, &adventurer, &options),
+            };
+            apply(game_board, &adventurer, action);
+            continue;
+        }
+        match game_board.phase() {
+            TurnPhase::Actions => {
+                let adventurer = game_board.current_adventurer();
+                let options = game_board.get_options(&adventurer, game_board.actions_left());
+                let action = agent.choose_action(game_board, &adventurer, &options);
+                apply(game_board, &adventurer, action);
+            }
+            TurnPhase::DrawTreasure => {
+                game_board.draw_treasure_cards(rng).unwrap();
+            }
+            TurnPhase::DrawFlood => game_board.draw_flood_cards(rng).unwrap(),
+        }
+    }
+    GameRecord {
+        outcome: game_board.outcome(),
+        turns: game_board.turn_number(),
+    }
+}
+
+/// Offers each adventurer holding a special card, other than the one the game is waiting
+/// on, the chance to play it. Returns `true` if one was played
+fn offer_special_cards(game_board: &mut GameBoard, agent: &mut dyn Agent) -> bool {
+    let deciding = match game_board.pending_decision() {
+        Some(decision) => Some(decision.adventurer()),
+        None if game_board.phase() == TurnPhase::Actions => Some(game_board.current_adventurer()),
+        None => None,
+    };
+    for adventurer in game_board.turn_order().to_vec() {
+        if Some(adventurer) == deciding {
+            continue;
+        }
+        let options: Vec<_> = game_board
+            .get_options(&adventurer, 0)
+            .into_iter()
+            .filter(Action::is_special_card)
+            .collect();
+        if options.is_empty() {
+            continue;
+        }
+        if let Some(action) = agent.choose_special(game_board, &adventurer, &options) {
+            apply(game_board, &adventurer, action);
+            return true;
+        }
+    }
+    false
+}
+
+/// Applies the action the agent picked for the adventurer
+fn apply(game_board: &mut GameBoard, adventurer: &AdventurerCardType, action: Action) {
+    if let Err(error) = game_board.apply(action.clone()) {
+        panic!("{adventurer:?} chose {action:?}, which is not allowed: {error}");
+    }
+}
+
+/// Returns the random number generator for game `game_index` of a batch. Every game
+/// draws from its own stream of the master seed, so can be replayed on its own
+pub fn game_rng(seed: u64, game_index: u64) -> ChaChaRng {
+    let mut rng = ChaChaRng::seed_from_u64(seed);
+    rng.set_stream(game_index);
+    rng
+}
+
+/// Plays the games of the batch across the worker threads and gathers up the results.
+/// Each game is played by a new agent from `new_agent`, given its own random number
+/// generator derived from the game's
+pub fn run_batch<F>(config: &BatchConfig, new_agent: F) -> BatchReport
+where
+    F: Fn(ChaChaRng) -> Box<dyn Agent> + Sync,
+{
+    let next_game = AtomicU64::new(0);
+    let play_games = || {
+        let mut report = BatchReport::default();
+        loop {
+            let start = next_game.fetch_add(GAMES_PER_CLAIM, Ordering::Relaxed);
+            if start >= config.games {
+                return report;
+            }
+            for game_index in start..(start + GAMES_PER_CLAIM).min(config.games) {
+                let mut rng = game_rng(config.seed, game_index);
+                let mut game_board =
+                    GameBoard::new(&mut rng, config.player_count, config.difficulty);
+                let mut agent = new_agent(ChaChaRng::from_rng(&mut rng).unwrap());
+                report.add(&play_game(&mut game_board, agent.as_mut(), &mut rng));
+            }
+        }
+    };
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..config.threads.max(1))
+            .map(|_| scope.spawn(play_games))
+            .collect();
+        workers
+            .into_iter()
+            .fold(BatchReport::default(), |mut report, worker| {
+                report.merge(&worker.join().unwrap());
+                report
+            })
+    })
+}
+
+/// Win and loss statistics gathered over a batch of games
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    games: usize,
+    wins: usize,
+    losses: HashMap<LossReason, usize>,
+    total_turns: u64,
+    total_turns_squared: u64,
+}
+
+impl BatchReport {
+    /// Counts a finished game towards the statistics
+    pub fn add(&mut self, record: &GameRecord) {
+        self.games += 1;
+        match record.outcome {
+            GameOutcome::Won => self.wins += 1,
+            GameOutcome::Lost(reason) => *self.losses.entry(reason).or_default() += 1,
+            GameOutcome::InProgress => panic!("The game has not finished"),
+        }
+        let turns = record.turns as u64;
+        self.total_turns += turns;
+        self.total_turns_squared += turns * turns;
+    }
+
+    /// Adds the games counted in `other` to `self`
+    pub fn merge(&mut self, other: &BatchReport) {
+        self.games += other.games;
+        self.wins += other.wins;
+        for (&reason, &count) in other.losses.iter() {
+            *self.losses.entry(reason).or_default() += count;
+        }
+        self.total_turns += other.total_turns;
+        self.total_turns_squared += other.total_turns_squared;
+    }
+
+    /// Returns the number of games played
+    pub fn games(&self) -> usize {
+        self.games
+    }
+
+    /// Returns the number of games won
+    pub fn wins(&self) -> usize {
+        self.wins
+    }
+
+    /// Returns the fraction of games won, or 0 if no games have been played
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    /// Returns the Wilson score 95% confidence interval of the win rate
+    pub fn win_rate_interval(&self) -> (f64, f64) {
+        if self.games == 0 {
+            return (0.0, 1.0);
+        }
+        let n = self.games as f64;
+        let p = self.win_rate();
+        let z2 = Z_95 * Z_95;
+        let centre = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let spread = Z_95 / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+        ((centre - spread).max(0.0), (centre + spread).min(1.0))
+    }
+
+    /// Returns how many games were lost for each reason, most common first
+    pub fn losses(&self) -> Vec<(LossReason, usize)> {
+        let mut losses: Vec<_> = self
+            .losses
+            .iter()
+            .map(|(&reason, &count)| (reason, count))
+            .collect();
+        losses.sort_by_cached_key(|&(reason, count)| (Reverse(count), format!("{reason:?}")));
+        losses
+    }
+
+    /// Returns the mean number of turns a game lasted, or 0 if no games have been played
+    pub fn mean_turns(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.total_turns as f64 / self.games as f64
+        }
+    }
+
+    /// Returns the normal approximation 95% confidence interval of the mean turns
+    pub fn mean_turns_interval(&self) -> (f64, f64) {
+        let mean = self.mean_turns();
+        if self.games < 2 {
+            return (mean, mean);
+        }
+        let n = self.games as f64;
+        let variance = (self.total_turns_squared as f64 - n * mean * mean) / (n - 1.0);
+        let spread = Z_95 * (variance.max(0.0) / n).sqrt();
+        (mean - spread, mean + spread)
+    }
+}
+
+impl fmt::Display for BatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (win_low, win_high) = self.win_rate_interval();
+        let (turns_low, turns_high) = self.mean_turns_interval();
+        writeln!(f, "Games played:   {}", self.games)?;
+        writeln!(
+            f,
+            "Win rate:       {:.2}% (95% CI {:.2}% - {:.2}%)",
+            self.win_rate() * 100.0,
+            win_low * 100.0,
+            win_high * 100.0
+        )?;
+        writeln!(
+            f,
+            "Turns survived: {:.2} (95% CI {:.2} - {:.2})",
+            self.mean_turns(),
+            turns_low,
+            turns_high
+        )?;
+        write!(f, "Losses:")?;
+        for (reason, count) in self.losses() {
+            write!(
+                f,
+                "\n  {:<32} {count:>8} ({:.2}%)",
+                format!("{reason:?}"),
+                count as f64 / self.games as f64 * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::strategy::{GreedyAgent, Strategy};
+
+    fn test_config() -> BatchConfig {
+        BatchConfig {
+            seed: 1,
+            games: 20,
+            player_count: 4,
+            difficulty: Difficulty::Normal,
+            threads: 1,
+        }
+    }
+
+    /// Plays greedily, checking every choice is offered at the right point and counting
+    /// how often each kind of decision comes up
+    struct CheckedAgent {
+        inner: GreedyAgent<ChaChaRng>,
+        discards: usize,
+        swims: usize,
+        specials: usize,
+    }
+
+    impl Agent for CheckedAgent {
+        fn choose_action(
+            &mut self,
+            game_board: &GameBoard,
+            adventurer: &AdventurerCardType,
+            options: &[Action],
+        ) -> Action {
+            assert_eq!(game_board.pending_decision(), None);
+            assert_eq!(adventurer, &game_board.current_adventurer());
+            self.inner.choose_action(game_board, adventurer, options)
+        }
+
+        fn choose_discard(
+            &mut self,
+            game_board: &GameBoard,
+            adventurer: &AdventurerCardType,
+            options: &[Action],
+        ) -> Action {
+            assert_eq!(
+                game_board.pending_decision(),
+                Some(Decision::Discard(*adventurer))
+            );
+            self.discards += 1;
+            self.inner.choose_discard(game_board, adventurer, options)
+        }
+
+        fn choose_swim(
+            &mut self,
+            game_board: &GameBoard,
+            adventurer: &AdventurerCardType,
+            options: &[Action],
+        ) -> Action {
+            assert_eq!(
+                game_board.pending_decision(),
+                Some(Decision::Swim(*adventurer))
+            );
+            self.swims += 1;
+            self.inner.choose_swim(game_board, adventurer, options)
+        }
+
+        fn choose_special(
+            &mut self,
+            game_board: &GameBoard,
+            adventurer: &AdventurerCardType,
+            options: &[Action],
+        ) -> Option<Action> {
+            assert!(options.iter().all(Action::is_special_card));
+            let action = self.inner.choose_special(game_board, adventurer, options);
+            self.specials += action.is_some() as usize;
+            action
+        }
+    }
+
+    #[test]
+    fn test_play_game_finishes() {
+        for &strategy in Strategy::iter() {
+            let mut rng = ChaChaRng::seed_from_u64(1);
+            let mut game_board = GameBoard::new(&mut rng, 2, Difficulty::Legendary);
+            let mut agent = strategy.agent(ChaChaRng::seed_from_u64(2));
+            let record = play_game(&mut game_board, agent.as_mut(), &mut rng);
+            assert_ne!(record.outcome, GameOutcome::InProgress);
+            assert_eq!(record.outcome, game_board.outcome());
+            assert!(record.turns >= 1);
+        }
+    }
+
+    #[test]
+    fn test_play_game_asks_agent() {
+        let mut agent = CheckedAgent {
+            inner: GreedyAgent::new(ChaChaRng::seed_from_u64(1)),
+            discards: 0,
+            swims: 0,
+            specials: 0,
+        };
+        for seed in 0..20 {
+            let mut rng = ChaChaRng::seed_from_u64(seed);
+            let mut game_board = GameBoard::new(&mut rng, 4, Difficulty::Novice);
+            play_game(&mut game_board, &mut agent, &mut rng);
+        }
+        assert!(agent.discards > 0);
+        assert!(agent.swims > 0);
+        assert!(agent.specials > 0);
+    }
+
+    #[test]
+    fn test_run_batch_is_repeatable() {
+        for &strategy in Strategy::iter() {
+            let config = test_config();
+            let report = run_batch(&config, |rng| strategy.agent(rng));
+            assert_eq!(report.games(), 20);
+            assert_eq!(
+                report.wins() + report.losses().iter().map(|(_, n)| n).sum::<usize>(),
+                20
+            );
+            assert_eq!(run_batch(&config, |rng| strategy.agent(rng)), report);
+        }
+    }
+
+    #[test]
+    fn test_run_batch_across_threads() {
+        let mut config = test_config();
+        config.difficulty = Difficulty::Legendary;
+        config.games = 3 * GAMES_PER_CLAIM + 5;
+        let new_agent = |rng| Strategy::Random.agent(rng);
+        let report = run_batch(&config, new_agent);
+        assert_eq!(report.games() as u64, config.games);
+        for threads in [2, 3, 8] {
+            config.threads = threads;
+            assert_eq!(run_batch(&config, new_agent), report, "{threads} threads");
+        }
+    }
+
+    #[test]
+    fn test_game_rng_streams() {
+        let draw = |game_index| game_rng(1, game_index).gen::<[u64; 4]>();
+        assert_eq!(draw(0), draw(0));
+        assert_ne!(draw(0), draw(1));
+        assert_ne!(draw(0), game_rng(2, 0).gen::<[u64; 4]>());
+    }
+
+    #[test]
+    fn test_report_statistics() {
+        let mut report = BatchReport::default();
+        for turns in [4, 6] {
+            report.add(&GameRecord {
+                outcome: GameOutcome::Won,
+                turns,
+            });
+        }
+        for _ in 0..2 {
+            report.add(&GameRecord {
+                outcome: GameOutcome::Lost(LossReason::FoolsLandingSunk),
+                turns: 5,
+            });
+        }
+        report.add(&GameRecord {
+            outcome: GameOutcome::Lost(LossReason::WaterMeterAtSkull),
+            turns: 5,
+        });
+
+        assert_eq!(report.win_rate(), 0.4);
+        assert_eq!(report.mean_turns(), 5.0);
+        assert_eq!(
+            report.losses(),
+            vec![
+                (LossReason::FoolsLandingSunk, 2),
+                (LossReason::WaterMeterAtSkull, 1)
+            ]
+        );
+        let (low, high) = report.win_rate_interval();
+        assert!(low < 0.4 && 0.4 < high);
+        assert!((0.0..=1.0).contains(&low) && (0.0..=1.0).contains(&high));
+        let (low, high) = report.mean_turns_interval();
+        assert!(low < 5.0 && 5.0 < high);
+    }
+}
@@ -0,0 +1,248 @@
+use std::slice::Iter;
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::{
+    action::Action,
+    agent::Agent,
+    cards::{
+        adventurer::AdventurerCardType,
+        island::{IslandCardName, IslandCardState},
+        treasure::{TreasureCardType, TreasureType},
+    },
+    coord::Coord,
+    game_board::{GameBoard, CARDS_TO_CAPTURE},
+};
+
+/// The built in ways of picking which action an adventurer takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    /// Picks any of the legal actions, all equally likely
+    Random,
+    /// Escapes and captures treasures as soon as it can, gives cards to whoever is
+    /// collecting them and keeps the tiles it needs shored up. Heads for a treasure tile
+    /// once it holds enough cards, for Fools' Landing once every treasure is captured,
+    /// and otherwise for the nearest flooded tile that is still needed
+    Greedy,
+}
+
+impl Strategy {
+    /// Returns every strategy
+    pub const fn all() -> [Strategy; 2] {
+        [Strategy::Random, Strategy::Greedy]
+    }
+
+    /// Returns an iterator over every strategy
+    pub fn iter() -> Iter<'static, Strategy> {
+        static ALL_STRATEGIES: [Strategy; 2] = Strategy::all();
+        ALL_STRATEGIES.iter()
+    }
+
+    /// Returns an agent that plays by this strategy, using `rng` for its random choices
+    pub fn agent<R>(&self, rng: R) -> Box<dyn Agent>
+    where
+        R: Rng + 'static,
+    {
+        match self {
+            Strategy::Random => Box::new(RandomAgent::new(rng)),
+            Strategy::Greedy => Box::new(GreedyAgent::new(rng)),
+        }
+    }
+}
+
+/// Plays by `Strategy::Random`
+#[derive(Debug, Clone)]
+pub struct RandomAgent<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> RandomAgent<R> {
+    /// Returns an agent that uses `rng` for its random choices
+    pub fn new(rng: R) -> RandomAgent<R> {
+        RandomAgent { rng }
+    }
+}
+
+impl<R: Rng> Agent for RandomAgent<R> {
+    fn choose_action(
+        &mut self,
+        _game_board: &GameBoard,
+        _adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Action {
+        options.choose(&mut self.rng).unwrap().clone()
+    }
+
+    /// Plays a special card half of the time, picking any of the options equally likely,
+    /// and holds on to them otherwise
+    fn choose_special(
+        &mut self,
+        _game_board: &GameBoard,
+        _adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Option<Action> {
+        if self.rng.gen_bool(0.5) {
+            options.choose(&mut self.rng).cloned()
+        } else {
+            None
+        }
+    }
+}
+
+/// Plays by `Strategy::Greedy`
+#[derive(Debug, Clone)]
+pub struct GreedyAgent<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> GreedyAgent<R> {
+    /// Returns an agent that uses `rng` to break ties
+    pub fn new(rng: R) -> GreedyAgent<R> {
+        GreedyAgent { rng }
+    }
+
+    /// Returns the highest scoring of the options and its score, picking at random
+    /// between those tied for the highest
+    fn best(
+        &mut self,
+        game_board: &GameBoard,
+        adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> (Action, i32) {
+        let scores: Vec<_> = options
+            .iter()
+            .map(|action| greedy_score(game_board, adventurer, action))
+            .collect();
+        let best = *scores.iter().max().unwrap();
+        let best_options: Vec<_> = options
+            .iter()
+            .zip(scores)
+            .filter(|&(_, score)| score == best)
+            .map(|(action, _)| action)
+            .collect();
+        ((*best_options.choose(&mut self.rng).unwrap()).clone(), best)
+    }
+}
+
+impl<R: Rng> Agent for GreedyAgent<R> {
+    fn choose_action(
+        &mut self,
+        game_board: &GameBoard,
+        adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Action {
+        self.best(game_board, adventurer, options).0
+    }
+
+    /// Only plays a special card out of turn to escape or save a tile that is needed
+    fn choose_special(
+        &mut self,
+        game_board: &GameBoard,
+        adventurer: &AdventurerCardType,
+        options: &[Action],
+    ) -> Option<Action> {
+        match self.best(game_board, adventurer, options) {
+            (action, score) if score > 0 => Some(action),
+            _ => None,
+        }
+    }
+}
+
+/// Scores how much the greedy strategy wants to take `action`, higher is better
+fn greedy_score(game_board: &GameBoard, adventurer: &AdventurerCardType, action: &Action) -> i32 {
+    let hand = game_board.get_adventurer(adventurer);
+    match action {
+        Action::Escape { .. } => 100,
+        Action::CaptureTreasure(_) => 90,
+        Action::GiveCard { to, treasure } => {
+            let card = TreasureCardType::Treasure(*treasure);
+            if game_board.get_adventurer(to).count_cards(&card) >= hand.count_cards(&card) {
+                70
+            } else {
+                0
+            }
+        }
+        Action::ShoreUp(tile) if is_needed(game_board, tile) => 60,
+        Action::ShoreUp(_) => 30,
+        Action::PlaySandbag { tile, .. } if is_needed(game_board, tile) => 55,
+        Action::Swim { to, .. } => match game_board.get_card(to).unwrap().state() {
+            IslandCardState::Normal => 51,
+            _ => 50,
+        },
+        Action::Discard { card, .. } => match card {
+            TreasureCardType::Treasure(treasure)
+                if game_board.captured_treasures().contains(treasure) =>
+            {
+                60
+            }
+            TreasureCardType::Treasure(_) => 50 - 5 * hand.count_cards(card) as i32,
+            _ => 20,
+        },
+        Action::Move(to) => {
+            let from = game_board.get_adventurer_location(adventurer);
+            let targets = greedy_targets(game_board, adventurer);
+            let closest = |coord: &Coord| targets.iter().map(|target| target.distance(coord)).min();
+            match (closest(&from), closest(to)) {
+                (Some(before), Some(after)) if after < before => 40 + (before - after) as i32,
+                _ => 0,
+            }
+        }
+        Action::Pass => 5,
+        _ => 0,
+    }
+}
+
+/// Returns `true` if the tile is Fools' Landing, or a treasure still to be captured can
+/// be captured from it
+fn is_needed(game_board: &GameBoard, tile: &IslandCardName) -> bool {
+    tile == &IslandCardName::FoolsLanding
+        || TreasureType::iter().any(|treasure| {
+            !game_board.captured_treasures().contains(treasure)
+                && game_board.get_tile(tile).can_retrieve(treasure)
+        })
+}
+
+/// Returns the tiles the adventurer is heading for: Fools' Landing once every treasure
+/// is captured, the tiles of a treasure they hold enough cards to capture, or failing
+/// that any flooded tile that is still needed and anyone they could give cards to
+fn greedy_targets(game_board: &GameBoard, adventurer: &AdventurerCardType) -> Vec<Coord> {
+    if game_board.captured_treasures().len() == TreasureType::all().len() {
+        return vec![game_board.get_location(&IslandCardName::FoolsLanding)];
+    }
+    let hand = game_board.get_adventurer(adventurer);
+    let targets: Vec<_> = TreasureType::iter()
+        .filter(|treasure| {
+            !game_board.captured_treasures().contains(treasure)
+                && hand.count_cards(&TreasureCardType::Treasure(**treasure)) >= CARDS_TO_CAPTURE
+        })
+        .flat_map(|treasure| {
+            IslandCardName::iter()
+                .map(|name| game_board.get_tile(name))
+                .filter(|tile| {
+                    tile.can_retrieve(treasure) && tile.state() != &IslandCardState::Sunk
+                })
+                .map(|tile| game_board.get_location(&tile.name()))
+        })
+        .collect();
+    if !targets.is_empty() {
+        return targets;
+    }
+    let collectors = game_board.turn_order().iter().filter(|other| {
+        other != &adventurer
+            && TreasureType::iter().any(|treasure| {
+                let card = TreasureCardType::Treasure(*treasure);
+                !game_board.captured_treasures().contains(treasure)
+                    && hand.count_cards(&card) > 0
+                    && game_board.get_adventurer(other).count_cards(&card)
+                        >= hand.count_cards(&card)
+            })
+    });
+    IslandCardName::iter()
+        .filter(|name| {
+            game_board.get_tile(name).state() == &IslandCardState::Flooded
+                && is_needed(game_board, name)
+        })
+        .map(|name| game_board.get_location(name))
+        .chain(collectors.map(|other| game_board.get_adventurer_location(other)))
+        .collect()
+}
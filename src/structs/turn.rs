@@ -0,0 +1,79 @@
+use std::fmt;
+
+use super::{cards::adventurer::AdventurerCardType, outcome::GameOutcome};
+
+/// The three phases every adventurer's turn passes through, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TurnPhase {
+    /// Take up to three actions
+    Actions,
+    /// Draw two cards from the treasure deck
+    DrawTreasure,
+    /// Draw flood cards equal to the water level
+    DrawFlood,
+}
+
+/// Tracks the use of abilities that are limited within a single turn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AbilityUsage {
+    /// The Pilot has flown to a tile this turn
+    pub pilot_flight: bool,
+    /// The Engineer has just shored up a tile and may shore up another for free
+    pub engineer_shore_up: bool,
+}
+
+/// A choice the game is paused on until the adventurer resolves it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Decision {
+    /// The adventurer is over the hand limit and must discard or play a card
+    Discard(AdventurerCardType),
+    /// The adventurer's tile sank and they must swim to another
+    Swim(AdventurerCardType),
+}
+
+impl Decision {
+    /// Returns the adventurer who has to make the decision
+    pub fn adventurer(&self) -> AdventurerCardType {
+        match self {
+            Decision::Discard(adventurer) | Decision::Swim(adventurer) => *adventurer,
+        }
+    }
+}
+
+/// Why a call was rejected because of where the game is in the turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnError {
+    /// The call is only valid during `expected`, but the turn is in `actual`
+    WrongPhase {
+        /// The phase the call is valid in
+        expected: TurnPhase,
+        /// The phase the turn is in
+        actual: TurnPhase,
+    },
+    /// All of the current adventurer's actions have been spent
+    NoActionsLeft,
+    /// The game is waiting on a decision to be resolved first
+    DecisionPending(Decision),
+    /// The game has already been won or lost
+    GameOver(GameOutcome),
+}
+
+impl fmt::Display for TurnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TurnError::WrongPhase { expected, actual } => {
+                write!(
+                    f,
+                    "expected the {expected:?} phase but the turn is in {actual:?}"
+                )
+            }
+            TurnError::NoActionsLeft => write!(f, "no actions left this turn"),
+            TurnError::DecisionPending(decision) => {
+                write!(f, "waiting on the decision {decision:?}")
+            }
+            TurnError::GameOver(outcome) => write!(f, "the game is over: {outcome:?}"),
+        }
+    }
+}
+
+impl std::error::Error for TurnError {}
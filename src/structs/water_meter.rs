@@ -0,0 +1,111 @@
+use std::slice::Iter;
+
+/// Where the water meter starts, as printed on the side of the meter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    /// Starts at level 1
+    Novice,
+    /// Starts at level 2
+    Normal,
+    /// Starts at level 3
+    Elite,
+    /// Starts at level 4
+    Legendary,
+}
+
+impl Difficulty {
+    /// Returns every difficulty
+    pub const fn all() -> [Difficulty; 4] {
+        [
+            Difficulty::Novice,
+            Difficulty::Normal,
+            Difficulty::Elite,
+            Difficulty::Legendary,
+        ]
+    }
+
+    /// Returns an iterator over every difficulty
+    pub fn iter() -> Iter<'static, Difficulty> {
+        static ALL_DIFFICULTIES: [Difficulty; 4] = Difficulty::all();
+        ALL_DIFFICULTIES.iter()
+    }
+
+    /// Returns the level the water meter starts at
+    pub fn start_level(&self) -> usize {
+        match self {
+            Difficulty::Novice => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Elite => 3,
+            Difficulty::Legendary => 4,
+        }
+    }
+}
+
+/// How high the water has risen, which sets how many flood cards are drawn each turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WaterMeter {
+    level: usize,
+}
+
+impl WaterMeter {
+    /// The level of the skull and crossbones, reaching it ends the game
+    pub const SKULL_LEVEL: usize = 10;
+
+    /// Returns a water meter at the starting level for `difficulty`
+    pub fn new(difficulty: Difficulty) -> WaterMeter {
+        WaterMeter {
+            level: difficulty.start_level(),
+        }
+    }
+
+    /// Returns the current level
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Returns the number of flood cards drawn each turn at the current level
+    pub fn flood_cards(&self) -> usize {
+        match self.level {
+            0..=2 => 2,
+            3..=5 => 3,
+            6..=7 => 4,
+            _ => 5,
+        }
+    }
+
+    /// Moves the water up one level, stopping at the skull
+    pub fn rise(&mut self) {
+        self.level = (self.level + 1).min(WaterMeter::SKULL_LEVEL);
+    }
+
+    /// Returns `true` if the water has reached the skull and crossbones
+    pub fn is_deadly(&self) -> bool {
+        self.level >= WaterMeter::SKULL_LEVEL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flood_cards() {
+        let mut water_meter = WaterMeter::new(Difficulty::Novice);
+        let mut flood_cards = vec![water_meter.flood_cards()];
+        while !water_meter.is_deadly() {
+            water_meter.rise();
+            flood_cards.push(water_meter.flood_cards());
+        }
+        assert_eq!(flood_cards, vec![2, 2, 3, 3, 3, 4, 4, 5, 5, 5]);
+    }
+
+    #[test]
+    fn test_rise_stops_at_skull() {
+        let mut water_meter = WaterMeter::new(Difficulty::Legendary);
+        for _ in 0..WaterMeter::SKULL_LEVEL {
+            water_meter.rise();
+        }
+        assert_eq!(water_meter.level(), WaterMeter::SKULL_LEVEL);
+        assert!(water_meter.is_deadly());
+    }
+}